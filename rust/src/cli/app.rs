@@ -1,5 +1,7 @@
 //! CLI 命令結構定義（clap derive）
 
+use crate::client::FilterCondition;
+use crate::secret::SecretBackend;
 use clap::{Parser, Subcommand};
 
 #[derive(Parser)]
@@ -25,6 +27,10 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub profile: Option<String>,
 
+    /// 以此登入帳號的身分呼叫（X-Redmine-Switch-User，需管理員 token）
+    #[arg(long, global = true)]
+    pub switch_user: Option<String>,
+
     #[command(subcommand)]
     pub command: Option<Command>,
 }
@@ -36,9 +42,21 @@ pub enum Command {
         /// Redmine URL
         #[arg(long)]
         url: String,
-        /// API Token
-        #[arg(long)]
-        token: String,
+        /// API Token（省略時在終端機上詢問，避免留在 shell history）
+        #[arg(long)]
+        token: Option<String>,
+        /// 取得 token 的命令（如 `op read op://vault/redmine/token`），每次執行時呼叫
+        #[arg(long, conflicts_with_all = ["token", "password"])]
+        token_command: Option<String>,
+        /// HTTP Basic 登入帳號（伺服器停用 REST API key 時）
+        #[arg(long, conflicts_with = "token")]
+        login: Option<String>,
+        /// HTTP Basic 密碼（省略時在終端機上詢問）
+        #[arg(long, requires = "login")]
+        password: Option<String>,
+        /// token 儲存方式：plaintext、encrypted（REDMINE_PASSPHRASE）或 helper:<name>
+        #[arg(long)]
+        secret_backend: Option<SecretBackend>,
         /// 存到全域 credentials.toml（預設存本地 .redmine）
         #[arg(short = 'g', long)]
         global: bool,
@@ -65,7 +83,14 @@ pub enum Command {
         all: bool,
     },
     /// 當前使用者
-    Me,
+    Me {
+        /// 顯示各專案的有效權限
+        #[arg(long)]
+        permissions: bool,
+        /// 只顯示此專案的權限
+        #[arg(long, requires = "permissions")]
+        project: Option<String>,
+    },
     /// 查看日誌 / 開啟 Web Log Viewer
     Log {
         /// 開啟 Web Log Viewer
@@ -76,6 +101,18 @@ pub enum Command {
         follow: bool,
     },
 
+    /// 管理 credentials.toml 中的 profiles
+    Profile {
+        #[command(subcommand)]
+        action: ProfileAction,
+    },
+
+    /// 中繼資料快取（trackers、狀態、優先權等）
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+
     /// Issues 操作
     Issues {
         #[command(subcommand)]
@@ -122,6 +159,12 @@ pub enum Command {
         /// 筆數上限
         #[arg(long, default_value = "25")]
         limit: u64,
+        /// 自動分頁取得全部（預設最多 1000 筆）
+        #[arg(long)]
+        all: bool,
+        /// --all 的筆數上限（最多 10000）
+        #[arg(long, requires = "all")]
+        max_items: Option<u64>,
     },
     /// Tracker 列表
     Trackers,
@@ -138,12 +181,20 @@ pub enum Command {
     Queries,
     /// 角色列表
     Roles,
-    /// 群組列表
-    Groups,
-    /// 新聞列表
+    /// 自訂欄位列表（需管理員權限）
+    CustomFields,
+    /// 群組操作（不帶子命令時列出群組）
+    Groups {
+        #[command(subcommand)]
+        action: Option<GroupsAction>,
+    },
+    /// 新聞操作（不帶子命令時列出新聞）
+    #[command(args_conflicts_with_subcommands = true)]
     News {
         /// 專案 ID（可選）
         project_id: Option<String>,
+        #[command(subcommand)]
+        action: Option<NewsAction>,
     },
     /// 通用 API 請求
     Api {
@@ -158,22 +209,95 @@ pub enum Command {
     },
 }
 
+// ========== Profile ==========
+
+#[derive(Subcommand)]
+pub enum ProfileAction {
+    /// 列出所有 profiles 與其設定
+    List,
+    /// 顯示 profile 設定（預設為目前使用中的 profile）
+    Show {
+        /// Profile 名稱
+        name: Option<String>,
+    },
+    /// 切換 profile（預設寫入本地 .redmine，-g 設為全域預設）
+    Use {
+        /// Profile 名稱
+        name: String,
+        /// 設為全域預設 profile
+        #[arg(short = 'g', long)]
+        global: bool,
+    },
+    /// 重新命名 profile（同步更新預設 profile 與本地 .redmine）
+    Rename {
+        /// 原名稱
+        old: String,
+        /// 新名稱
+        new: String,
+    },
+    /// 複製 profile
+    Copy {
+        /// 來源 profile
+        from: String,
+        /// 新 profile 名稱
+        to: String,
+    },
+    /// 修改 profile 的 URL
+    SetUrl {
+        /// Profile 名稱
+        name: String,
+        /// 新的 Redmine URL
+        url: String,
+    },
+    /// 更換 token（或 Basic 密碼），沿用原本的 secret 後端；未以 --token 指定時在終端機上詢問
+    RotateToken {
+        /// Profile 名稱（預設為目前使用中的 profile）
+        name: Option<String>,
+    },
+    /// 並行檢查所有 profiles 能否連線
+    Verify,
+}
+
+// ========== Cache ==========
+
+#[derive(Subcommand)]
+pub enum CacheAction {
+    /// 清除所有 profile 的快取
+    Clear,
+}
+
 // ========== Issues ==========
 
 #[derive(Subcommand)]
 pub enum IssuesAction {
     /// Issues 列表
     List {
+        /// 專案 ID、識別碼或名稱
         #[arg(long)]
         project_id: Option<String>,
+        /// open、closed、*、狀態 ID 或名稱（! 開頭表示排除）
         #[arg(long)]
         status: Option<String>,
+        /// 使用者 ID、登入帳號、姓名或 me
         #[arg(long)]
         assigned_to: Option<String>,
-        #[arg(long)]
-        tracker_id: Option<u64>,
+        /// Tracker ID 或名稱
+        #[arg(long)]
+        tracker_id: Option<String>,
+        /// 執行已儲存查詢（名稱或 ID；Redmine 會忽略其他篩選，因此不能與篩選參數並用）
+        #[arg(long, conflicts_with_all = ["status", "assigned_to", "tracker_id", "filters"])]
+        query: Option<String>,
+        /// 進階篩選 field:op[:value]，可重複但每個欄位只能一次；多值以 | 分隔（例如 updated_on:>t-:7、created_on:><:2024-01-01|2024-01-31）
+        #[arg(long = "filter", value_name = "FIELD:OP[:VALUE]")]
+        filters: Vec<FilterCondition>,
         #[arg(long, default_value = "25")]
         limit: u64,
+        /// 自動分頁取得全部（預設最多 1000 筆）
+        #[arg(long)]
+        all: bool,
+        /// --all 的筆數上限（最多 10000）
+        #[arg(long, requires = "all")]
+        max_items: Option<u64>,
         #[arg(long)]
         offset: Option<u64>,
         #[arg(long)]
@@ -183,6 +307,9 @@ pub enum IssuesAction {
     Show {
         /// Issue ID
         id: u64,
+        /// 以 Markdown 顯示描述（依 REDMINE_TEXT_FORMAT 轉換）
+        #[arg(long)]
+        markdown: bool,
     },
     /// 更新 Issue
     Update {
@@ -190,19 +317,110 @@ pub enum IssuesAction {
         id: u64,
         #[arg(long)]
         notes: Option<String>,
+        /// 備註設為私人
+        #[arg(long)]
+        private: bool,
+        #[arg(long)]
+        description: Option<String>,
+        /// notes / description 為 Markdown，自動轉換為伺服器格式
+        #[arg(long)]
+        markdown: bool,
+        /// 目標狀態名稱或 ID（依工作流程驗證）
+        #[arg(long, conflicts_with = "status_id")]
+        status: Option<String>,
         #[arg(long)]
         status_id: Option<u64>,
+        /// 使用者 ID、登入帳號、姓名或 me
+        #[arg(long)]
+        assigned_to_id: Option<String>,
+        /// 優先權 ID 或名稱
         #[arg(long)]
-        assigned_to_id: Option<u64>,
+        priority_id: Option<String>,
+        /// 目標版本 ID
         #[arg(long)]
-        priority_id: Option<u64>,
+        fixed_version_id: Option<u64>,
         #[arg(long)]
         done_ratio: Option<u8>,
     },
+    /// 批次更新多個 Issue（先預覽，--yes 或確認後才送出）
+    #[command(group(clap::ArgGroup::new("selection").required(true).multiple(true).args(["ids", "query", "filters"])))]
+    BulkUpdate {
+        /// Issue ID（逗號分隔）
+        #[arg(long, value_delimiter = ',')]
+        ids: Vec<u64>,
+        /// 以已儲存查詢選取（名稱或 ID；不能與 --ids、--filter 並用）
+        #[arg(long, conflicts_with_all = ["ids", "filters"])]
+        query: Option<String>,
+        /// 以進階篩選選取 field:op[:value]，可重複但每個欄位只能一次
+        #[arg(long = "filter", value_name = "FIELD:OP[:VALUE]", conflicts_with = "ids")]
+        filters: Vec<FilterCondition>,
+        /// 限定專案（搭配 --query / --filter）
+        #[arg(long)]
+        project_id: Option<String>,
+        /// 目標狀態名稱或 ID（逐一依工作流程驗證）
+        #[arg(long)]
+        status: Option<String>,
+        /// 使用者 ID、登入帳號、姓名或 me
+        #[arg(long)]
+        assigned_to_id: Option<String>,
+        /// 優先權 ID 或名稱
+        #[arg(long)]
+        priority_id: Option<String>,
+        /// 目標版本 ID
+        #[arg(long)]
+        fixed_version_id: Option<u64>,
+        #[arg(long)]
+        done_ratio: Option<u8>,
+        #[arg(long)]
+        notes: Option<String>,
+        /// 備註設為私人
+        #[arg(long)]
+        private: bool,
+        /// 並行數（最多 10）
+        #[arg(long, default_value = "4")]
+        concurrency: usize,
+        /// 選取筆數上限
+        #[arg(long)]
+        max_items: Option<u64>,
+        /// 不詢問，直接套用
+        #[arg(short, long)]
+        yes: bool,
+    },
     /// Issue 歷史
     Journals {
         /// Issue ID
         id: u64,
+        /// 以 Markdown 顯示備註
+        #[arg(long)]
+        markdown: bool,
+    },
+    /// 新增備註，或以 --journal 修改既有備註
+    Comment {
+        /// Issue ID
+        id: u64,
+        /// 備註內容
+        #[arg(short, long, conflicts_with = "editor")]
+        message: Option<String>,
+        /// 以 $VISUAL / $EDITOR 編輯備註
+        #[arg(long)]
+        editor: bool,
+        /// 設為私人備註；搭配 --journal 時可用 --private=false 取消
+        #[arg(long, num_args = 0..=1, default_missing_value = "true", require_equals = true)]
+        private: Option<bool>,
+        /// 修改既有 Journal 的備註（Redmine 5+）
+        #[arg(long)]
+        journal: Option<u64>,
+        /// 刪除 --journal 指定的備註
+        #[arg(long, requires = "journal", conflicts_with_all = ["message", "editor"])]
+        delete: bool,
+        /// 備註為 Markdown，自動轉換為伺服器格式
+        #[arg(long)]
+        markdown: bool,
+    },
+    /// Issue 關聯的版本庫提交
+    Commits {
+        /// Issue ID
+        id: u64,
     },
 }
 
@@ -210,12 +428,24 @@ pub enum IssuesAction {
 
 #[derive(Subcommand)]
 pub enum ProjectsAction {
-    /// 專案列表
-    List,
-    /// 專案成員
+    /// 專案列表（自動分頁）
+    List {
+        /// 筆數上限（預設與上限 10000）
+        #[arg(long)]
+        max_items: Option<u64>,
+    },
+    /// 專案成員（自動分頁）
     Members {
         /// 專案 ID
         project_id: String,
+        /// 筆數上限（預設與上限 10000）
+        #[arg(long)]
+        max_items: Option<u64>,
+    },
+    /// 專案的主版本庫（需 REDMINE_SYS_API_KEY）
+    Repositories {
+        /// 專案 ID
+        project_id: String,
     },
 }
 
@@ -227,16 +457,85 @@ pub enum UsersAction {
     List {
         #[arg(long)]
         name: Option<String>,
+        /// 群組 ID 或名稱
         #[arg(long)]
-        group_id: Option<u64>,
+        group_id: Option<String>,
         #[arg(long, default_value = "25")]
         limit: u64,
+        /// 自動分頁取得全部（預設最多 1000 筆）
+        #[arg(long)]
+        all: bool,
+        /// --all 的筆數上限（最多 10000）
+        #[arg(long, requires = "all")]
+        max_items: Option<u64>,
     },
     /// 使用者詳情
     Show {
         /// 使用者 ID
         id: u64,
     },
+    /// 建立使用者（需管理員權限）
+    Create {
+        #[arg(long)]
+        login: String,
+        #[arg(long)]
+        firstname: String,
+        #[arg(long)]
+        lastname: String,
+        #[arg(long)]
+        mail: String,
+        /// 密碼（或用 --generate-password）
+        #[arg(long)]
+        password: Option<String>,
+        /// 由伺服器產生密碼並寄信通知
+        #[arg(long, conflicts_with = "password")]
+        generate_password: bool,
+        /// 首次登入須變更密碼
+        #[arg(long)]
+        must_change_password: bool,
+        /// 認證來源 ID（LDAP 等）
+        #[arg(long)]
+        auth_source_id: Option<u64>,
+        /// 設為管理員
+        #[arg(long)]
+        admin: bool,
+    },
+    /// 更新使用者（需管理員權限）
+    Update {
+        /// 使用者 ID
+        id: u64,
+        #[arg(long)]
+        login: Option<String>,
+        #[arg(long)]
+        firstname: Option<String>,
+        #[arg(long)]
+        lastname: Option<String>,
+        #[arg(long)]
+        mail: Option<String>,
+        #[arg(long)]
+        password: Option<String>,
+        /// 認證來源 ID（LDAP 等）
+        #[arg(long)]
+        auth_source_id: Option<u64>,
+        /// 管理員權限 (true/false)
+        #[arg(long)]
+        admin: Option<bool>,
+    },
+    /// 鎖定使用者（需管理員權限）
+    Lock {
+        /// 使用者 ID
+        id: u64,
+    },
+    /// 解鎖使用者（需管理員權限）
+    Unlock {
+        /// 使用者 ID
+        id: u64,
+    },
+    /// 刪除使用者（需管理員權限）
+    Rm {
+        /// 使用者 ID
+        id: u64,
+    },
 }
 
 // ========== Time ==========
@@ -245,8 +544,10 @@ pub enum UsersAction {
 pub enum TimeAction {
     /// 工時列表
     List {
+        /// 專案 ID、識別碼或名稱
         #[arg(long)]
         project_id: Option<String>,
+        /// 使用者 ID、登入帳號、姓名或 me
         #[arg(long)]
         user_id: Option<String>,
         #[arg(long)]
@@ -255,6 +556,12 @@ pub enum TimeAction {
         to: Option<String>,
         #[arg(long, default_value = "25")]
         limit: u64,
+        /// 自動分頁取得全部（預設最多 1000 筆）
+        #[arg(long)]
+        all: bool,
+        /// --all 的筆數上限（最多 10000）
+        #[arg(long, requires = "all")]
+        max_items: Option<u64>,
     },
     /// 建立工時
     Log {
@@ -264,12 +571,12 @@ pub enum TimeAction {
         /// Issue ID
         #[arg(long)]
         issue_id: Option<u64>,
-        /// 專案 ID
+        /// 專案 ID、識別碼或名稱
         #[arg(long)]
         project_id: Option<String>,
-        /// 活動類型 ID
+        /// 活動類型 ID 或名稱
         #[arg(long)]
-        activity_id: Option<u64>,
+        activity_id: Option<String>,
         /// 備註
         #[arg(long)]
         comments: Option<String>,
@@ -312,9 +619,38 @@ pub enum WikiAction {
         project_id: String,
         /// 頁面標題
         title: String,
+        /// 指定版本（預設最新）
+        #[arg(long)]
+        version: Option<u64>,
+        /// 以 Markdown 顯示（依 REDMINE_TEXT_FORMAT 轉換）
+        #[arg(long)]
+        markdown: bool,
+    },
+    /// Wiki 頁面歷史
+    History {
+        /// 專案 ID
+        project_id: String,
+        /// 頁面標題
+        title: String,
+        /// 最多顯示幾個版本
+        #[arg(long)]
+        limit: Option<u64>,
+    },
+    /// 比較 Wiki 頁面版本（預設最新版與前一版）
+    Diff {
+        /// 專案 ID
+        project_id: String,
+        /// 頁面標題
+        title: String,
+        /// 舊版本號
+        #[arg(long)]
+        from: Option<u64>,
+        /// 新版本號
+        #[arg(long)]
+        to: Option<u64>,
     },
     /// 更新 Wiki 頁面（格式取決於 Redmine 設定：Textile 或 Markdown）
-    #[command(after_help = "文字格式:\n  內容格式由 Redmine 管理員設定決定（管理 > 設定 > 一般 > 文字格式）\n  常見格式：\n    textile  — h1. 標題、*粗體*、|_. 表頭|\n    markdown — # 標題、**粗體**、| 表頭 |")]
+    #[command(after_help = "文字格式:\n  內容格式由 Redmine 管理員設定決定（管理 > 設定 > 一般 > 文字格式）\n  常見格式：\n    textile  — h1. 標題、*粗體*、|_. 表頭|\n    markdown — # 標題、**粗體**、| 表頭 |\n  使用 --markdown 時以 Markdown 撰寫，依 REDMINE_TEXT_FORMAT 自動轉換")]
     Update {
         /// 專案 ID
         project_id: String,
@@ -329,6 +665,158 @@ pub enum WikiAction {
         /// 備註
         #[arg(long)]
         comments: Option<String>,
+        /// 預期的目前版本（版本不符時拒絕覆蓋）
+        #[arg(long)]
+        version: Option<u64>,
+        /// 父頁面標題
+        #[arg(long)]
+        parent: Option<String>,
+        /// 內容為 Markdown，自動轉換為伺服器格式
+        #[arg(long)]
+        markdown: bool,
+    },
+    /// 重新命名 Wiki 頁面（新頁面不保留版本歷史）
+    Rename {
+        /// 專案 ID
+        project_id: String,
+        /// 目前標題
+        title: String,
+        /// 新標題
+        new_title: String,
+        /// 子頁面全部改掛成功後刪除舊頁面（會永久失去舊頁面的版本歷史與附件）
+        #[arg(long)]
+        delete_old: bool,
+    },
+    /// 刪除 Wiki 頁面（子頁面移至頂層）
+    #[command(alias = "rm")]
+    Delete {
+        /// 專案 ID
+        project_id: String,
+        /// 頁面標題
+        title: String,
+    },
+    /// 下載所有 Wiki 頁面到本地目錄（兩邊都有修改時回報衝突）
+    Pull {
+        /// 專案 ID
+        project_id: String,
+        /// 本地目錄
+        dir: String,
+        /// 頁面檔案副檔名
+        #[arg(long, default_value = "textile")]
+        ext: String,
+        /// 只顯示將執行的動作
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// 上傳本地目錄中有修改的頁面（遠端已更新時回報衝突）
+    Push {
+        /// 專案 ID
+        project_id: String,
+        /// 本地目錄
+        dir: String,
+        /// 頁面檔案副檔名
+        #[arg(long, default_value = "textile")]
+        ext: String,
+        /// 只顯示將執行的動作
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+// ========== Groups ==========
+
+#[derive(Subcommand)]
+pub enum GroupsAction {
+    /// 群組列表
+    List,
+    /// 群組詳情（成員與專案角色）
+    Show {
+        /// 群組 ID 或名稱
+        group: String,
+    },
+    /// 建立群組（需管理員權限）
+    Create {
+        /// 群組名稱
+        name: String,
+        /// 初始成員 ID（逗號分隔）
+        #[arg(long, value_delimiter = ',')]
+        user_ids: Vec<u64>,
+    },
+    /// 刪除群組（需管理員權限）
+    Rm {
+        /// 群組 ID 或名稱
+        group: String,
+    },
+    /// 將使用者加入群組（需管理員權限）
+    AddUser {
+        /// 群組 ID 或名稱
+        group: String,
+        /// 使用者 ID、登入帳號或姓名
+        user: String,
+    },
+    /// 將使用者移出群組（需管理員權限）
+    RmUser {
+        /// 群組 ID 或名稱
+        group: String,
+        /// 使用者 ID、登入帳號或姓名
+        user: String,
+    },
+}
+
+// ========== News ==========
+
+#[derive(Subcommand)]
+pub enum NewsAction {
+    /// 新聞列表
+    List {
+        /// 專案 ID（可選）
+        #[arg(long)]
+        project_id: Option<String>,
+        #[arg(long, default_value = "25")]
+        limit: u64,
+        #[arg(long)]
+        offset: Option<u64>,
+    },
+    /// 新聞詳情（含留言）
+    Show {
+        /// 新聞 ID
+        id: u64,
+    },
+    /// 發布新聞
+    Post {
+        /// 專案 ID
+        project_id: String,
+        /// 標題
+        #[arg(long)]
+        title: String,
+        /// 摘要
+        #[arg(long)]
+        summary: Option<String>,
+        /// 內容（或用 --file 讀檔）
+        #[arg(long)]
+        description: Option<String>,
+        /// 從檔案讀取內容
+        #[arg(long)]
+        file: Option<String>,
+    },
+    /// 更新新聞
+    Edit {
+        /// 新聞 ID
+        id: u64,
+        #[arg(long)]
+        title: Option<String>,
+        #[arg(long)]
+        summary: Option<String>,
+        #[arg(long)]
+        description: Option<String>,
+        /// 從檔案讀取內容
+        #[arg(long)]
+        file: Option<String>,
+    },
+    /// 刪除新聞
+    Rm {
+        /// 新聞 ID
+        id: u64,
     },
 }
 
@@ -341,13 +829,19 @@ pub enum FilesAction {
         /// 專案 ID
         project_id: String,
     },
-    /// 上傳檔案
+    /// 上傳檔案（指定 --project 時加入專案檔案區）
     Upload {
         /// 檔案路徑
         file: String,
         /// 說明
         #[arg(long)]
         description: Option<String>,
+        /// 加入此專案的檔案區
+        #[arg(long)]
+        project: Option<String>,
+        /// 關聯版本（名稱或 ID，需搭配 --project）
+        #[arg(long, requires = "project")]
+        version: Option<String>,
     },
     /// 下載附件
     Download {
@@ -357,4 +851,34 @@ pub enum FilesAction {
         #[arg(long, short)]
         output: Option<String>,
     },
+    /// 附件資訊
+    Show {
+        /// 附件 ID
+        id: u64,
+    },
+    /// 更新附件檔名或說明
+    Edit {
+        /// 附件 ID
+        id: u64,
+        #[arg(long)]
+        filename: Option<String>,
+        #[arg(long)]
+        description: Option<String>,
+    },
+    /// 刪除附件
+    Rm {
+        /// 附件 ID
+        id: u64,
+    },
+    /// 下載附件縮圖（僅圖片附件）
+    Thumbnail {
+        /// 附件 ID
+        id: u64,
+        /// 縮圖邊長像素
+        #[arg(long)]
+        size: Option<u32>,
+        /// 儲存路徑（預設 thumbnail-{id}.{ext}）
+        #[arg(long, short)]
+        output: Option<String>,
+    },
 }
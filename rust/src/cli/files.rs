@@ -3,6 +3,7 @@
 use anyhow::Context;
 use crate::cli::app::FilesAction;
 use crate::cli::output::Output;
+use crate::client::*;
 use crate::RedmineClient;
 
 pub async fn run(client: &RedmineClient, out: &Output, action: FilesAction) -> anyhow::Result<()> {
@@ -18,16 +19,34 @@ pub async fn run(client: &RedmineClient, out: &Output, action: FilesAction) -> a
                         f.id.to_string(),
                         f.filename.clone(),
                         format_size(f.filesize),
+                        f.version.as_ref().map(|v| v.name.clone()).unwrap_or_default(),
                         f.created_on.clone(),
                     ]
                 })
                 .collect();
-            out.print_table(&["ID", "Filename", "Size", "Created"], rows, &serde_json::to_value(&resp)?);
+            out.print_table(&["ID", "Filename", "Size", "Version", "Created"], rows, &serde_json::to_value(&resp)?);
         }
-        FilesAction::Upload { file, description } => {
+        FilesAction::Upload { file, description, project, version } => {
+            let version_id = match (&project, &version) {
+                (Some(p), Some(v)) => Some(resolve_version(client, p, v).await?),
+                _ => None,
+            };
             let resp = client.upload_file(&file, description.as_deref()).await
                 .with_context(|| format!("上傳檔案 {file} 失敗"))?;
-            out.print_ok(&format!("上傳成功 (token: {})", resp.upload.token));
+            match project {
+                Some(project_id) => {
+                    let params = ProjectFileParams {
+                        token: resp.upload.token,
+                        version_id,
+                        filename: None,
+                        description,
+                    };
+                    client.create_project_file(&project_id, &params).await
+                        .with_context(|| format!("加入專案 {project_id} 檔案區失敗"))?;
+                    out.print_ok(&format!("已上傳 {file} 至專案 {project_id}"));
+                }
+                None => out.print_ok(&format!("上傳成功 (token: {})", resp.upload.token)),
+            }
         }
         FilesAction::Download { id, output } => {
             let save_path = match output {
@@ -42,10 +61,69 @@ pub async fn run(client: &RedmineClient, out: &Output, action: FilesAction) -> a
                 .with_context(|| format!("下載附件 #{id} 失敗"))?;
             out.print_ok(&format!("已下載: {} → {}", result.filename, result.saved_to));
         }
+        FilesAction::Show { id } => {
+            let resp = client.get_attachment(id).await
+                .with_context(|| format!("取得附件 #{id} 資訊失敗"))?;
+            let a = &resp.attachment;
+            let pairs: Vec<(&str, String)> = vec![
+                ("ID", a.id.to_string()),
+                ("Filename", a.filename.clone()),
+                ("Size", format_size(a.filesize)),
+                ("Type", a.content_type.clone().unwrap_or("-".into())),
+                ("Description", a.description.clone().unwrap_or("-".into())),
+                ("Author", a.author.name.clone()),
+                ("Created", a.created_on.clone()),
+                ("URL", a.content_url.clone()),
+            ];
+            out.print_detail(&pairs, &serde_json::to_value(&resp)?);
+        }
+        FilesAction::Edit { id, filename, description } => {
+            if filename.is_none() && description.is_none() {
+                anyhow::bail!("請提供 --filename 或 --description");
+            }
+            let params = AttachmentUpdateParams { filename, description };
+            client.update_attachment(id, &params).await
+                .with_context(|| format!("更新附件 #{id} 失敗"))?;
+            out.print_ok(&format!("附件 #{id} 已更新"));
+        }
+        FilesAction::Rm { id } => {
+            client.delete_attachment(id).await
+                .with_context(|| format!("刪除附件 #{id} 失敗"))?;
+            out.print_ok(&format!("附件 #{id} 已刪除"));
+        }
+        FilesAction::Thumbnail { id, size, output } => {
+            let thumbnail = client.get_attachment_thumbnail(id, size).await
+                .with_context(|| format!("取得附件 #{id} 縮圖失敗"))?;
+            let save_path = output.unwrap_or_else(|| {
+                let ext = thumbnail
+                    .content_type
+                    .as_deref()
+                    .and_then(|t| t.strip_prefix("image/"))
+                    .unwrap_or("png");
+                format!("thumbnail-{id}.{ext}")
+            });
+            tokio::fs::write(&save_path, &thumbnail.data).await
+                .with_context(|| format!("寫入 {save_path} 失敗"))?;
+            out.print_ok(&format!("縮圖已儲存: {save_path}"));
+        }
     }
     Ok(())
 }
 
+/// 版本名稱或 ID → 版本 ID
+async fn resolve_version(client: &RedmineClient, project_id: &str, version: &str) -> anyhow::Result<u64> {
+    if let Ok(id) = version.parse::<u64>() {
+        return Ok(id);
+    }
+    let resp = client.get_versions(project_id).await
+        .with_context(|| format!("取得版本列表失敗 (project: {project_id})"))?;
+    resp.versions
+        .iter()
+        .find(|v| v.name == version)
+        .map(|v| v.id)
+        .ok_or_else(|| anyhow::anyhow!("專案 {project_id} 找不到版本 '{version}'"))
+}
+
 fn format_size(bytes: u64) -> String {
     const KB: u64 = 1024;
     const MB: u64 = 1024 * 1024;
@@ -0,0 +1,81 @@
+//! Groups 子命令
+
+use anyhow::Context;
+use crate::cli::app::GroupsAction;
+use crate::cli::output::Output;
+use crate::client::*;
+use crate::RedmineClient;
+
+pub async fn run(client: &RedmineClient, out: &Output, action: GroupsAction) -> anyhow::Result<()> {
+    match action {
+        GroupsAction::List => {
+            let resp = client.get_groups().await.context("取得群組列表失敗")?;
+            let items: Vec<(u64, String)> = resp.groups.iter().map(|g| (g.id, g.name.clone())).collect();
+            out.print_id_name_list("Groups:", &items, &serde_json::to_value(&resp)?);
+        }
+        GroupsAction::Show { group } => {
+            let id = find_group(client, &group).await?;
+            let resp = client.get_group(id).await
+                .with_context(|| format!("取得群組 #{id} 失敗"))?;
+            let g = &resp.group;
+            let users = g.users.as_deref().unwrap_or_default();
+            let pairs: Vec<(&str, String)> = vec![
+                ("ID", g.id.to_string()),
+                ("Name", g.name.clone()),
+                ("Users", users.len().to_string()),
+            ];
+            out.print_detail(&pairs, &serde_json::to_value(&resp)?);
+            if !out.json {
+                if !users.is_empty() {
+                    println!("\nMembers:");
+                    for u in users {
+                        println!("  {:>5}  {}", u.id, u.name);
+                    }
+                }
+                let memberships = g.memberships.as_deref().unwrap_or_default();
+                if !memberships.is_empty() {
+                    println!("\nProjects:");
+                    for m in memberships {
+                        let roles: Vec<&str> = m.roles.iter().map(|r| r.name.as_str()).collect();
+                        println!("  {}  ({})", m.project.name, roles.join(", "));
+                    }
+                }
+            }
+        }
+        GroupsAction::Create { name, user_ids } => {
+            let params = GroupParams {
+                name: Some(name.clone()),
+                user_ids: (!user_ids.is_empty()).then_some(user_ids),
+            };
+            let resp = client.create_group(&params).await
+                .with_context(|| format!("建立群組 {name} 失敗"))?;
+            out.print_ok(&format!("群組 {} 已建立 (ID: {})", resp.group.name, resp.group.id));
+        }
+        GroupsAction::Rm { group } => {
+            let id = find_group(client, &group).await?;
+            client.delete_group(id).await
+                .with_context(|| format!("刪除群組 #{id} 失敗"))?;
+            out.print_ok(&format!("群組 #{id} 已刪除"));
+        }
+        GroupsAction::AddUser { group, user } => {
+            let id = find_group(client, &group).await?;
+            let user_id = client.resolve_user(&user, None).await?;
+            client.add_user_to_group(id, user_id).await
+                .with_context(|| format!("將使用者 #{user_id} 加入群組 #{id} 失敗"))?;
+            out.print_ok(&format!("使用者 #{user_id} 已加入群組 #{id}"));
+        }
+        GroupsAction::RmUser { group, user } => {
+            let id = find_group(client, &group).await?;
+            let user_id = client.resolve_user(&user, None).await?;
+            client.remove_user_from_group(id, user_id).await
+                .with_context(|| format!("將使用者 #{user_id} 移出群組 #{id} 失敗"))?;
+            out.print_ok(&format!("使用者 #{user_id} 已移出群組 #{id}"));
+        }
+    }
+    Ok(())
+}
+
+async fn find_group(client: &RedmineClient, group: &str) -> anyhow::Result<u64> {
+    client.resolve_group(group).await
+        .with_context(|| format!("找不到群組 '{group}'"))
+}
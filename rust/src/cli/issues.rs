@@ -4,6 +4,8 @@ use anyhow::Context;
 use crate::cli::app::IssuesAction;
 use crate::cli::output::Output;
 use crate::client::*;
+use crate::markup;
+use crate::shell;
 use crate::RedmineClient;
 
 pub async fn run(client: &RedmineClient, out: &Output, action: IssuesAction) -> anyhow::Result<()> {
@@ -13,21 +15,50 @@ pub async fn run(client: &RedmineClient, out: &Output, action: IssuesAction) ->
             status,
             assigned_to,
             tracker_id,
+            query,
+            filters,
             limit,
+            all,
+            max_items,
             offset,
             sort,
         } => {
-            let params = IssueListParams {
+            let project_id = match project_id {
+                Some(p) => Some(client.resolve_project(&p).await?),
+                None => None,
+            };
+            let status_id = match status {
+                Some(s) => Some(client.resolve_status_filter(&s).await?),
+                None => None,
+            };
+            let assigned_to_id = match assigned_to {
+                Some(u) => Some(client.resolve_user_filter(&u, project_id.as_deref()).await?),
+                None => None,
+            };
+            let tracker_id = match tracker_id {
+                Some(t) => Some(client.resolve_tracker(&t).await?),
+                None => None,
+            };
+            let mut params = IssueListParams {
                 project_id: project_id.clone(),
-                status_id: status,
-                assigned_to_id: assigned_to,
+                status_id,
+                assigned_to_id,
                 tracker_id,
                 limit: Some(limit),
                 offset,
                 sort,
+                query_id: None,
+                filters: filters.into(),
             };
-            let resp = client.get_issues(&params).await
-                .with_context(|| format!("取得 Issues 失敗 (project: {:?})", project_id))?;
+            if let Some(query) = &query {
+                client.apply_saved_query(&mut params, query).await
+                    .with_context(|| format!("找不到已儲存查詢 '{query}'"))?;
+            }
+            let resp = match super::fetch_all_limit(all, max_items) {
+                Some(max_items) => client.get_all_issues(&params, Some(max_items)).await,
+                None => client.get_issues(&params).await,
+            }
+            .with_context(|| format!("取得 Issues 失敗 (project: {:?})", project_id))?;
             let rows: Vec<Vec<String>> = resp
                 .issues
                 .iter()
@@ -51,9 +82,12 @@ pub async fn run(client: &RedmineClient, out: &Output, action: IssuesAction) ->
                 println!("({}/{})", resp.issues.len(), resp.total_count);
             }
         }
-        IssuesAction::Show { id } => {
-            let resp = client.get_issue(id).await
+        IssuesAction::Show { id, markdown } => {
+            let mut resp = client.get_issue(id).await
                 .with_context(|| format!("取得 Issue #{id} 失敗"))?;
+            if markdown {
+                markup::issue_to_markdown(&mut resp.issue, client.text_format());
+            }
             let i = &resp.issue;
             let pairs: Vec<(&str, String)> = vec![
                 ("ID", i.id.to_string()),
@@ -61,6 +95,13 @@ pub async fn run(client: &RedmineClient, out: &Output, action: IssuesAction) ->
                 ("Project", i.project.name.clone()),
                 ("Tracker", i.tracker.name.clone()),
                 ("Status", i.status.name.clone()),
+                (
+                    "Transitions",
+                    i.allowed_statuses
+                        .as_ref()
+                        .map(|s| s.iter().map(|s| s.name.as_str()).collect::<Vec<_>>().join(", "))
+                        .unwrap_or("-".into()),
+                ),
                 ("Priority", i.priority.name.clone()),
                 ("Author", i.author.name.clone()),
                 ("Assignee", i.assigned_to.as_ref().map(|a| a.name.clone()).unwrap_or("-".into())),
@@ -81,25 +122,146 @@ pub async fn run(client: &RedmineClient, out: &Output, action: IssuesAction) ->
         IssuesAction::Update {
             id,
             notes,
+            private,
+            description,
+            markdown,
+            status,
             status_id,
             assigned_to_id,
             priority_id,
+            fixed_version_id,
             done_ratio,
         } => {
+            let (notes, description) = if markdown {
+                let format = client.text_format();
+                (
+                    notes.map(|t| markup::markdown_to_server(&t, format)),
+                    description.map(|t| markup::markdown_to_server(&t, format)),
+                )
+            } else {
+                (notes, description)
+            };
+            let status_id = match status.or(status_id.map(|s| s.to_string())) {
+                Some(status) => Some(
+                    client.resolve_issue_status(id, &status).await
+                        .with_context(|| format!("Issue #{id} 狀態驗證失敗"))?,
+                ),
+                None => None,
+            };
+            let assigned_to_id = match assigned_to_id {
+                Some(u) => Some(client.resolve_user(&u, None).await?),
+                None => None,
+            };
+            let priority_id = match priority_id {
+                Some(p) => Some(client.resolve_priority(&p).await?),
+                None => None,
+            };
             let params = IssueUpdateParams {
+                private_notes: (private && notes.is_some()).then_some(true),
                 notes,
+                description,
                 status_id,
                 assigned_to_id,
                 priority_id,
+                fixed_version_id,
                 done_ratio,
             };
             client.update_issue(id, &params).await
                 .with_context(|| format!("更新 Issue #{id} 失敗"))?;
             out.print_ok(&format!("Issue #{id} 已更新"));
         }
-        IssuesAction::Journals { id } => {
-            let resp = client.get_journals(id).await
+        IssuesAction::BulkUpdate {
+            ids,
+            query,
+            filters,
+            project_id,
+            status,
+            assigned_to_id,
+            priority_id,
+            fixed_version_id,
+            done_ratio,
+            notes,
+            private,
+            concurrency,
+            max_items,
+            yes,
+        } => {
+            let project_id = match project_id {
+                Some(p) => Some(client.resolve_project(&p).await?),
+                None => None,
+            };
+            let assigned_to_id = match assigned_to_id {
+                Some(u) => Some(client.resolve_user(&u, project_id.as_deref()).await?),
+                None => None,
+            };
+            let priority_id = match priority_id {
+                Some(p) => Some(client.resolve_priority(&p).await?),
+                None => None,
+            };
+            let changes = IssueUpdateParams {
+                private_notes: (private && notes.is_some()).then_some(true),
+                notes,
+                assigned_to_id,
+                priority_id,
+                fixed_version_id,
+                done_ratio,
+                ..Default::default()
+            };
+            if changes.is_empty() && status.is_none() {
+                anyhow::bail!("沒有指定要變更的欄位");
+            }
+
+            let mut params = if ids.is_empty() {
+                IssueListParams {
+                    project_id,
+                    filters: filters.into(),
+                    ..Default::default()
+                }
+            } else {
+                IssueListParams::for_ids(&ids)
+            };
+            if let Some(query) = &query {
+                client.apply_saved_query(&mut params, query).await
+                    .with_context(|| format!("找不到已儲存查詢 '{query}'"))?;
+            }
+            let issues = client.get_all_issues(&params, max_items).await
+                .context("取得要更新的 Issues 失敗")?
+                .issues;
+            if issues.is_empty() {
+                out.print_ok("沒有符合條件的 Issue");
+                return Ok(());
+            }
+
+            if !yes {
+                let preview = client
+                    .bulk_update_issues(&issues, &changes, status.as_deref(), true, concurrency)
+                    .await;
+                print_bulk_report(out, &preview);
+                if preview.failed > 0 {
+                    anyhow::bail!("{} 個 Issue 驗證失敗，未送出任何修改", preview.failed);
+                }
+                if out.json || !confirm(&format!("套用到 {} 個 Issue？", preview.total))? {
+                    if !out.json {
+                        println!("未送出任何修改（加上 --yes 直接套用）");
+                    }
+                    return Ok(());
+                }
+            }
+
+            let report = client
+                .bulk_update_issues(&issues, &changes, status.as_deref(), false, concurrency)
+                .await;
+            print_bulk_report(out, &report);
+            if report.failed > 0 {
+                anyhow::bail!("{}/{} 個 Issue 更新失敗", report.failed, report.total);
+            }
+        }
+        IssuesAction::Journals { id, markdown } => {
+            let mut resp = client.get_journals(id).await
                 .with_context(|| format!("取得 Issue #{id} 歷史失敗"))?;
+            if markdown {
+                markup::issue_to_markdown(&mut resp.issue, client.text_format());
+            }
             let journals = resp.issue.journals.clone().unwrap_or_default();
             let rows: Vec<Vec<String>> = journals
                 .iter()
@@ -120,7 +282,10 @@ pub async fn run(client: &RedmineClient, out: &Output, action: IssuesAction) ->
                         j.id.to_string(),
                         j.user.name.clone(),
                         j.created_on.clone(),
-                        j.notes.clone().unwrap_or_default(),
+                        match (j.private_notes, j.notes.clone().unwrap_or_default()) {
+                            (true, notes) => format!("[private] {notes}"),
+                            (false, notes) => notes,
+                        },
                         changes.join("; "),
                     ]
                 })
@@ -131,6 +296,163 @@ pub async fn run(client: &RedmineClient, out: &Output, action: IssuesAction) ->
                 &serde_json::to_value(&resp)?,
             );
         }
+        IssuesAction::Comment {
+            id,
+            message,
+            editor,
+            private,
+            journal,
+            delete,
+            markdown,
+        } => {
+            if delete {
+                let journal_id = journal.unwrap_or_default();
+                let params = JournalParams {
+                    notes: Some(String::new()),
+                    private_notes: None,
+                };
+                client.update_journal(journal_id, &params).await
+                    .with_context(|| format!("刪除備註 #{journal_id} 失敗"))?;
+                out.print_ok(&format!("備註 #{journal_id} 已刪除"));
+                return Ok(());
+            }
+
+            let notes = match (message, editor) {
+                (Some(m), _) => Some(m),
+                (None, true) => {
+                    let initial = match journal {
+                        Some(journal_id) => find_journal_notes(client, id, journal_id).await?,
+                        None => String::new(),
+                    };
+                    Some(edit_in_editor(&initial)?)
+                }
+                (None, false) if journal.is_some() => None,
+                (None, false) => anyhow::bail!("請以 -m 提供備註內容，或使用 --editor"),
+            };
+            let notes = if markdown {
+                notes.map(|t| markup::markdown_to_server(&t, client.text_format()))
+            } else {
+                notes
+            };
+
+            match journal {
+                Some(journal_id) => {
+                    let params = JournalParams {
+                        notes,
+                        private_notes: private,
+                    };
+                    client.update_journal(journal_id, &params).await
+                        .with_context(|| format!("修改備註 #{journal_id} 失敗"))?;
+                    out.print_ok(&format!("備註 #{journal_id} 已更新"));
+                }
+                None => {
+                    let notes = notes.unwrap_or_default();
+                    client.add_issue_note(id, &notes, private.unwrap_or(false)).await
+                        .with_context(|| format!("新增 Issue #{id} 備註失敗"))?;
+                    out.print_ok(&format!("已新增備註到 Issue #{id}"));
+                }
+            }
+        }
+        IssuesAction::Commits { id } => {
+            let changesets = client.get_issue_changesets(id).await
+                .with_context(|| format!("取得 Issue #{id} 關聯提交失敗"))?;
+            let rows: Vec<Vec<String>> = changesets
+                .iter()
+                .map(|c| {
+                    vec![
+                        c.revision.clone(),
+                        c.user.as_ref().map(|u| u.name.clone()).unwrap_or_default(),
+                        c.committed_on.clone().unwrap_or_default(),
+                        c.comments
+                            .as_deref()
+                            .and_then(|s| s.lines().next())
+                            .unwrap_or_default()
+                            .to_string(),
+                    ]
+                })
+                .collect();
+            out.print_table(
+                &["Revision", "Author", "Date", "Message"],
+                rows,
+                &serde_json::to_value(&changesets)?,
+            );
+        }
     }
     Ok(())
 }
+
+/// 取得 Issue 中指定 Journal 的備註，作為編輯器初始內容
+async fn find_journal_notes(client: &RedmineClient, issue_id: u64, journal_id: u64) -> anyhow::Result<String> {
+    let resp = client.get_journals(issue_id).await
+        .with_context(|| format!("取得 Issue #{issue_id} 歷史失敗"))?;
+    resp.issue
+        .journals
+        .unwrap_or_default()
+        .into_iter()
+        .find(|j| j.id == journal_id)
+        .map(|j| j.notes.unwrap_or_default())
+        .with_context(|| format!("Issue #{issue_id} 沒有 Journal #{journal_id}"))
+}
+
+/// 以 $VISUAL / $EDITOR（預設 vi）編輯文字；內容為空時取消
+fn edit_in_editor(initial: &str) -> anyhow::Result<String> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| if cfg!(windows) { "notepad" } else { "vi" }.to_string());
+    let path = std::env::temp_dir().join(format!("redmine-comment-{}.txt", std::process::id()));
+    std::fs::write(&path, initial).with_context(|| format!("寫入 {} 失敗", path.display()))?;
+
+    // EDITOR 可能帶參數（例如 "code --wait"），交給 shell 解析
+    let status = shell::command(&format!("{editor} {}", shell::quote(&path.to_string_lossy())))
+        .status()
+        .with_context(|| format!("啟動編輯器 {editor} 失敗"));
+    let content = std::fs::read_to_string(&path);
+    let _ = std::fs::remove_file(&path);
+
+    if !status?.success() {
+        anyhow::bail!("編輯器 {editor} 非正常結束，已取消");
+    }
+    let content = content.with_context(|| format!("讀取 {} 失敗", path.display()))?;
+    let content = content.trim_end().to_string();
+    if content.is_empty() {
+        anyhow::bail!("備註內容為空，已取消");
+    }
+    Ok(content)
+}
+
+/// 輸出批次更新的逐筆結果
+fn print_bulk_report(out: &Output, report: &BulkUpdateReport) {
+    let rows: Vec<Vec<String>> = report
+        .results
+        .iter()
+        .map(|r| {
+            let result = match (&r.error, report.dry_run) {
+                (Some(e), _) => format!("失敗: {e}"),
+                (None, true) => "可更新".into(),
+                (None, false) => "已更新".into(),
+            };
+            vec![r.id.to_string(), r.status.clone(), r.subject.clone(), result]
+        })
+        .collect();
+    out.print_table(
+        &["#", "Status", "Subject", "Result"],
+        rows,
+        &serde_json::to_value(report).unwrap_or_default(),
+    );
+    if !out.json {
+        println!("({} 成功 / {} 失敗)", report.succeeded, report.failed);
+    }
+}
+
+/// 在終端機詢問 y/N；非互動模式一律視為否
+fn confirm(prompt: &str) -> anyhow::Result<bool> {
+    use std::io::{IsTerminal, Write};
+    if !std::io::stdin().is_terminal() {
+        return Ok(false);
+    }
+    print!("{prompt} [y/N] ");
+    std::io::stdout().flush()?;
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+}
@@ -82,29 +82,21 @@ pub async fn roles(client: &RedmineClient, out: &Output) -> anyhow::Result<()> {
     Ok(())
 }
 
-pub async fn groups(client: &RedmineClient, out: &Output) -> anyhow::Result<()> {
-    let resp = client.get_groups().await.context("取得群組列表失敗")?;
-    let items: Vec<(u64, String)> = resp.groups.iter().map(|g| (g.id, g.name.clone())).collect();
-    out.print_id_name_list("Groups:", &items, &serde_json::to_value(&resp)?);
-    Ok(())
-}
-
-pub async fn news(client: &RedmineClient, out: &Output, project_id: Option<&str>) -> anyhow::Result<()> {
-    let resp = client.get_news(project_id).await.context("取得新聞失敗")?;
+pub async fn custom_fields(client: &RedmineClient, out: &Output) -> anyhow::Result<()> {
+    let resp = client.get_custom_fields().await.context("取得自訂欄位失敗")?;
     let rows: Vec<Vec<String>> = resp
-        .news
+        .custom_fields
         .iter()
-        .map(|n| {
+        .map(|f| {
             vec![
-                n.id.to_string(),
-                n.project.name.clone(),
-                n.title.clone(),
-                n.author.name.clone(),
-                n.created_on.clone(),
+                f.id.to_string(),
+                f.name.clone(),
+                f.customized_type.clone(),
+                f.field_format.clone(),
             ]
         })
         .collect();
-    out.print_table(&["ID", "Project", "Title", "Author", "Date"], rows, &serde_json::to_value(&resp)?);
+    out.print_table(&["ID", "Name", "Type", "Format"], rows, &serde_json::to_value(&resp)?);
     Ok(())
 }
 
@@ -114,14 +106,18 @@ pub async fn search(
     query: &str,
     project_id: Option<&str>,
     limit: u64,
+    all: Option<u64>,
 ) -> anyhow::Result<()> {
     let params = SearchParams {
         project_id: project_id.map(String::from),
         limit: Some(limit),
         ..Default::default()
     };
-    let resp = client.search(query, &params).await
-        .with_context(|| format!("搜尋 '{query}' 失敗"))?;
+    let resp = match all {
+        Some(max_items) => client.search_all(query, &params, Some(max_items)).await,
+        None => client.search(query, &params).await,
+    }
+    .with_context(|| format!("搜尋 '{query}' 失敗"))?;
     let rows: Vec<Vec<String>> = resp
         .results
         .iter()
@@ -149,7 +145,7 @@ pub async fn api(
     data: Option<&str>,
 ) -> anyhow::Result<()> {
     let data_value: Option<serde_json::Value> = data
-        .map(|d| serde_json::from_str(d))
+        .map(serde_json::from_str)
         .transpose()
         .context("JSON data 解析失敗")?;
     let resp = client.request(path, method, data_value.as_ref(), None).await
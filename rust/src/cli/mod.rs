@@ -8,13 +8,20 @@ mod users;
 mod time;
 mod versions;
 mod wiki;
+mod wiki_sync;
 mod files;
 mod metadata;
+mod groups;
+mod news;
+mod profiles;
 
 pub use app::Cli;
+use anyhow::Context;
 use app::Command;
 use output::Output;
-use crate::credential::{CredentialFile, LocalConfig, Profile};
+use crate::client::MetadataCache;
+use crate::credential::{CredentialFile, CredentialSource, LocalConfig, Profile};
+use crate::secret::{self, SecretBackend};
 use crate::Config;
 use crate::RedmineClient;
 
@@ -22,8 +29,36 @@ pub async fn run(cli: Cli) -> anyhow::Result<()> {
     let out = Output::new(cli.json);
 
     match &cli.command {
-        Some(Command::Login { url, token, global, profile, set_default }) => {
-            return do_login(url, token, *global, profile.as_deref(), *set_default, &out).await;
+        Some(Command::Login {
+            url,
+            token,
+            token_command,
+            login,
+            password,
+            secret_backend,
+            global,
+            profile,
+            set_default,
+        }) => {
+            let secret = match (token_command, login, token.as_ref().or(password.as_ref())) {
+                (Some(_), _, _) => String::new(),
+                (None, _, Some(secret)) => secret.clone(),
+                (None, Some(_), None) => secret::prompt_hidden("Password: ")?,
+                (None, None, None) => secret::prompt_hidden("API Token: ")?,
+            };
+            let credential = Profile {
+                url: url.clone(),
+                token: if login.is_none() { secret.clone() } else { String::new() },
+                encrypted_token: None,
+                credential_helper: None,
+                token_command: token_command.clone(),
+                login: login.clone(),
+                password: login.as_ref().map(|_| secret),
+                switch_user: None,
+                connection: Default::default(),
+            };
+            let backend = secret_backend.clone();
+            return do_login(credential, backend, *global, profile.as_deref(), *set_default, &out).await;
         }
         Some(Command::Logout { global, profile }) => {
             return do_logout(*global, profile.as_deref(), &out);
@@ -31,6 +66,12 @@ pub async fn run(cli: Cli) -> anyhow::Result<()> {
         Some(Command::Log { web, follow }) => {
             return do_log(*web, *follow).await;
         }
+        Some(Command::Profile { action }) => {
+            return profiles::run(&out, action, cli.profile.as_deref(), cli.token.as_deref()).await;
+        }
+        Some(Command::Cache { action: app::CacheAction::Clear }) => {
+            return do_cache_clear(&out);
+        }
         None => {
             use clap::CommandFactory;
             Cli::command().print_help()?;
@@ -40,16 +81,28 @@ pub async fn run(cli: Cli) -> anyhow::Result<()> {
     }
 
     // 其餘命令需要憑證 — 提前驗證
-    let (config, resolved) = Config::resolve(
+    let (mut config, resolved) = Config::resolve(
         cli.url.as_deref(),
         cli.token.as_deref(),
         cli.profile.as_deref(),
     )?;
-    let client = RedmineClient::new(&config.redmine_url, &config.redmine_token)?;
+    if let Some(user) = &cli.switch_user {
+        config.switch_user = Some(user.clone());
+    }
+    if config.switch_user.is_some() {
+        init_audit_log(&config.log_file);
+    }
+    let client = config
+        .client_builder()
+        .cache(MetadataCache::on_disk(&config.redmine_url, config.cache_ttl))
+        .build()?;
 
     match cli.command.unwrap() {
         Command::Status { all } => do_status(&client, &out, &resolved, all).await,
-        Command::Me => do_me(&client, &out).await,
+        Command::Me { permissions: false, .. } => do_me(&client, &out).await,
+        Command::Me { permissions: true, project } => {
+            do_my_permissions(&client, &out, project.as_deref()).await
+        }
         Command::Issues { action } => issues::run(&client, &out, action).await,
         Command::Projects { action } => projects::run(&client, &out, action).await,
         Command::Users { action } => users::run(&client, &out, action).await,
@@ -57,8 +110,9 @@ pub async fn run(cli: Cli) -> anyhow::Result<()> {
         Command::Versions { action } => versions::run(&client, &out, action).await,
         Command::Wiki { action } => wiki::run(&client, &out, action).await,
         Command::Files { action } => files::run(&client, &out, action).await,
-        Command::Search { query, project_id, limit } => {
-            metadata::search(&client, &out, &query, project_id.as_deref(), limit).await
+        Command::Search { query, project_id, limit, all, max_items } => {
+            let all = fetch_all_limit(all, max_items);
+            metadata::search(&client, &out, &query, project_id.as_deref(), limit, all).await
         }
         Command::Trackers => metadata::trackers(&client, &out).await,
         Command::Statuses => metadata::statuses(&client, &out).await,
@@ -66,14 +120,26 @@ pub async fn run(cli: Cli) -> anyhow::Result<()> {
         Command::Categories { project_id } => metadata::categories(&client, &out, &project_id).await,
         Command::Queries => metadata::queries(&client, &out).await,
         Command::Roles => metadata::roles(&client, &out).await,
-        Command::Groups => metadata::groups(&client, &out).await,
-        Command::News { project_id } => {
-            metadata::news(&client, &out, project_id.as_deref()).await
+        Command::CustomFields => metadata::custom_fields(&client, &out).await,
+        Command::Groups { action } => {
+            groups::run(&client, &out, action.unwrap_or(app::GroupsAction::List)).await
+        }
+        Command::News { project_id, action } => {
+            let action = action.unwrap_or(app::NewsAction::List {
+                project_id,
+                limit: 25,
+                offset: None,
+            });
+            news::run(&client, &out, action).await
         }
         Command::Api { path, method, data } => {
             metadata::api(&client, &out, &path, &method, data.as_deref()).await
         }
-        Command::Login { .. } | Command::Logout { .. } | Command::Log { .. } => unreachable!(),
+        Command::Login { .. }
+        | Command::Logout { .. }
+        | Command::Log { .. }
+        | Command::Profile { .. }
+        | Command::Cache { .. } => unreachable!(),
     }
 }
 
@@ -86,28 +152,45 @@ fn profile_name_from_url(url: &str) -> String {
 }
 
 async fn do_login(
-    url: &str,
-    token: &str,
+    mut credential: Profile,
+    backend: Option<SecretBackend>,
     global: bool,
     profile_name: Option<&str>,
     set_default: bool,
     out: &Output,
 ) -> anyhow::Result<()> {
-    // 先驗證連線
-    let client = RedmineClient::new(url, token)?;
+    let name = profile_name
+        .map(String::from)
+        .unwrap_or_else(|| profile_name_from_url(&credential.url));
+
+    // 重新登入時保留 profile 原有的代理、TLS 與代理使用者設定
+    let mut cred_file = CredentialFile::load_or_default()?;
+    if let Some(existing) = cred_file.get_profile(&name) {
+        credential.connection = existing.connection.clone();
+        credential.switch_user = existing.switch_user.clone();
+    }
+
+    // 先以 token 本身的身分驗證連線
+    let mut config = Config::from_resolved(&credential.resolve(&name, CredentialSource::CliFlags)?)?;
+    config.switch_user = None;
+    let client = config.client_builder().build()?;
     let resp = client.get_current_user().await?;
     let u = &resp.user;
 
-    let name = profile_name
-        .map(String::from)
-        .unwrap_or_else(|| profile_name_from_url(url));
+    // 依 secret 後端儲存 token（token_command 不需儲存）
+    if credential.token_command.is_none() {
+        let secret = credential.secret(&name)?;
+        let backend = backend
+            .or_else(|| cred_file.secret_backend.clone())
+            .unwrap_or_default();
+        if let Some(existing) = cred_file.get_profile(&name) {
+            existing.erase_secret(&name)?;
+        }
+        credential.store_secret(&name, &secret, &backend)?;
+    }
 
     // 儲存 profile 到全域 credentials.toml
-    let mut cred_file = CredentialFile::load_or_default();
-    cred_file.profiles.insert(name.clone(), Profile {
-        url: url.to_string(),
-        token: token.to_string(),
-    });
+    cred_file.profiles.insert(name.clone(), credential);
 
     // 第一個 profile 或明確指定 → 設為預設
     if set_default || cred_file.default_profile.is_none() {
@@ -137,8 +220,9 @@ async fn do_login(
 fn do_logout(global: bool, profile_name: Option<&str>, out: &Output) -> anyhow::Result<()> {
     if global {
         if let Some(name) = profile_name {
-            let mut cred_file = CredentialFile::load_or_default();
-            if cred_file.profiles.remove(name).is_some() {
+            let mut cred_file = CredentialFile::load_or_default()?;
+            if let Some(profile) = cred_file.profiles.remove(name) {
+                profile.erase_secret(name)?;
                 if cred_file.default_profile.as_deref() == Some(name) {
                     cred_file.default_profile = None;
                 }
@@ -148,6 +232,9 @@ fn do_logout(global: bool, profile_name: Option<&str>, out: &Output) -> anyhow::
                 out.print_ok(&format!("Profile '{name}' 不存在"));
             }
         } else {
+            for (name, profile) in &CredentialFile::load_or_default()?.profiles {
+                profile.erase_secret(name)?;
+            }
             CredentialFile::remove()?;
             out.print_ok("已移除全部全域憑證");
         }
@@ -178,7 +265,7 @@ async fn do_status(
     out.print_detail(&pairs, &serde_json::to_value(&resp)?);
 
     if show_all {
-        let cred_file = CredentialFile::load_or_default();
+        let cred_file = CredentialFile::load_or_default()?;
         let default = cred_file.default_profile.as_deref();
         println!("\nProfiles:");
         if cred_file.profiles.is_empty() {
@@ -198,9 +285,28 @@ async fn do_status(
     Ok(())
 }
 
+/// 代理使用者時記錄 `audit` 事件（X-Redmine-Switch-User 呼叫），附加寫入 LOG_FILE；無法開啟時改寫到 stderr
+fn init_audit_log(log_file: &str) {
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::new("audit=info"))
+        .with_ansi(false);
+    match std::fs::OpenOptions::new().create(true).append(true).open(log_file) {
+        Ok(file) => builder.with_writer(std::sync::Mutex::new(file)).init(),
+        Err(_) => builder.with_writer(std::io::stderr).init(),
+    }
+}
+
+fn do_cache_clear(out: &Output) -> anyhow::Result<()> {
+    if MetadataCache::clear_all().context("清除快取失敗")? {
+        out.print_ok("已清除中繼資料快取");
+    } else {
+        out.print_ok("沒有快取需要清除");
+    }
+    Ok(())
+}
+
 async fn do_log(web: bool, follow: bool) -> anyhow::Result<()> {
-    let log_file = std::env::var("LOG_FILE")
-        .unwrap_or_else(|_| "/tmp/redmine-mcp.log".into());
+    let log_file = Config::default_log_file();
     let path = std::path::Path::new(&log_file);
 
     if web {
@@ -280,3 +386,32 @@ async fn do_me(client: &RedmineClient, out: &Output) -> anyhow::Result<()> {
     out.print_detail(&pairs, &serde_json::to_value(&resp)?);
     Ok(())
 }
+
+async fn do_my_permissions(
+    client: &RedmineClient,
+    out: &Output,
+    project: Option<&str>,
+) -> anyhow::Result<()> {
+    let perms = client.get_my_permissions(project).await.context("取得權限失敗")?;
+    let rows: Vec<Vec<String>> = perms
+        .projects
+        .iter()
+        .map(|p| {
+            vec![
+                p.project.name.clone(),
+                if p.roles.is_empty() { "-".into() } else { p.roles.join(", ") },
+                p.permissions.join(", "),
+            ]
+        })
+        .collect();
+    out.print_table(&["Project", "Roles", "Permissions"], rows, &serde_json::to_value(&perms)?);
+    if !out.json && perms.admin {
+        println!("(管理員：不受角色限制)");
+    }
+    Ok(())
+}
+
+/// `--all` / `--max-items` 轉為自動分頁的筆數上限；未指定 `--all` 時回傳 None
+fn fetch_all_limit(all: bool, max_items: Option<u64>) -> Option<u64> {
+    all.then(|| crate::client::clamp_max_items(max_items))
+}
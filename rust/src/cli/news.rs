@@ -0,0 +1,103 @@
+//! News 子命令
+
+use anyhow::Context;
+use crate::cli::app::NewsAction;
+use crate::cli::output::Output;
+use crate::client::*;
+use crate::RedmineClient;
+
+pub async fn run(client: &RedmineClient, out: &Output, action: NewsAction) -> anyhow::Result<()> {
+    match action {
+        NewsAction::List { project_id, limit, offset } => {
+            let params = NewsListParams {
+                limit: Some(limit),
+                offset,
+            };
+            let resp = client.get_news(project_id.as_deref(), &params).await.context("取得新聞失敗")?;
+            let rows: Vec<Vec<String>> = resp
+                .news
+                .iter()
+                .map(|n| {
+                    vec![
+                        n.id.to_string(),
+                        n.project.name.clone(),
+                        n.title.clone(),
+                        n.author.name.clone(),
+                        n.created_on.clone(),
+                    ]
+                })
+                .collect();
+            out.print_table(&["ID", "Project", "Title", "Author", "Date"], rows, &serde_json::to_value(&resp)?);
+            if !out.json {
+                println!("({}/{})", resp.news.len(), resp.total_count);
+            }
+        }
+        NewsAction::Show { id } => {
+            let resp = client.get_news_item(id).await
+                .with_context(|| format!("取得新聞 #{id} 失敗"))?;
+            let n = &resp.news;
+            let pairs: Vec<(&str, String)> = vec![
+                ("ID", n.id.to_string()),
+                ("Title", n.title.clone()),
+                ("Project", n.project.name.clone()),
+                ("Author", n.author.name.clone()),
+                ("Summary", n.summary.clone().unwrap_or("-".into())),
+                ("Created", n.created_on.clone()),
+            ];
+            out.print_detail(&pairs, &serde_json::to_value(&resp)?);
+            if !out.json {
+                if let Some(desc) = &n.description {
+                    if !desc.is_empty() {
+                        println!("\n--- Description ---\n{desc}");
+                    }
+                }
+                let comments = n.comments.as_deref().unwrap_or_default();
+                if !comments.is_empty() {
+                    println!("\n--- Comments ({}) ---", comments.len());
+                    for c in comments {
+                        println!("[{}] {}", c.author.name, c.content.as_deref().unwrap_or(""));
+                    }
+                }
+            }
+        }
+        NewsAction::Post { project_id, title, summary, description, file } => {
+            let description = read_description(description, file).await?;
+            let params = NewsParams {
+                title: Some(title.clone()),
+                summary,
+                description,
+            };
+            client.create_news(&project_id, &params).await
+                .with_context(|| format!("發布新聞失敗 (project: {project_id})"))?;
+            out.print_ok(&format!("新聞「{title}」已發布"));
+        }
+        NewsAction::Edit { id, title, summary, description, file } => {
+            let description = read_description(description, file).await?;
+            let params = NewsParams {
+                title,
+                summary,
+                description,
+            };
+            client.update_news(id, &params).await
+                .with_context(|| format!("更新新聞 #{id} 失敗"))?;
+            out.print_ok(&format!("新聞 #{id} 已更新"));
+        }
+        NewsAction::Rm { id } => {
+            client.delete_news(id).await
+                .with_context(|| format!("刪除新聞 #{id} 失敗"))?;
+            out.print_ok(&format!("新聞 #{id} 已刪除"));
+        }
+    }
+    Ok(())
+}
+
+/// --file 優先於 --description
+async fn read_description(description: Option<String>, file: Option<String>) -> anyhow::Result<Option<String>> {
+    match file {
+        Some(f) => Ok(Some(
+            tokio::fs::read_to_string(&f).await
+                .with_context(|| format!("讀取檔案 {f} 失敗"))?,
+        )),
+        None => Ok(description),
+    }
+}
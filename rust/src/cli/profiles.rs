@@ -0,0 +1,282 @@
+//! Profile 子命令 — list, show, use, rename, copy, set-url, rotate-token, verify
+
+use anyhow::{bail, Context};
+use crate::cli::app::ProfileAction;
+use crate::cli::output::Output;
+use crate::config::Config;
+use crate::credential::{CredentialFile, CredentialSource, LocalConfig, Profile};
+use crate::secret;
+use serde_json::{json, Value};
+
+pub async fn run(
+    out: &Output,
+    action: &ProfileAction,
+    cli_profile: Option<&str>,
+    cli_token: Option<&str>,
+) -> anyhow::Result<()> {
+    let mut cred_file = CredentialFile::load_or_default()?;
+    match action {
+        ProfileAction::List => list(out, &cred_file),
+        ProfileAction::Show { name } => {
+            let name = name.clone().or_else(|| current_profile(cli_profile, &cred_file)).context(
+                "沒有使用中的 profile，請指定名稱",
+            )?;
+            show(out, &cred_file, &name)
+        }
+        ProfileAction::Use { name, global } => {
+            get(&cred_file, name)?;
+            if *global {
+                cred_file.default_profile = Some(name.clone());
+                cred_file.save()?;
+                out.print_ok(&format!("已將 '{name}' 設為全域預設 profile"));
+            } else {
+                let path = LocalConfig { profile: name.clone() }.save_to_cwd()?;
+                out.print_ok(&format!("本地 .redmine → profile '{name}' ({})", path.display()));
+            }
+            Ok(())
+        }
+        ProfileAction::Rename { old, new } => rename(out, &mut cred_file, old, new),
+        ProfileAction::Copy { from, to } => {
+            if cred_file.profiles.contains_key(to) {
+                bail!("Profile '{to}' 已存在");
+            }
+            let profile = get(&cred_file, from)?.clone();
+            profile.copy_helper_secret(from, &profile, to)?;
+            cred_file.profiles.insert(to.clone(), profile);
+            cred_file.save()?;
+            out.print_ok(&format!("已複製 profile '{from}' → '{to}'"));
+            Ok(())
+        }
+        ProfileAction::SetUrl { name, url } => {
+            let old = get(&cred_file, name)?.clone();
+            let mut profile = old.clone();
+            profile.url = url.trim_end_matches('/').to_string();
+            if old.url != profile.url {
+                old.copy_helper_secret(name, &profile, name)?;
+                old.erase_secret(name)?;
+            }
+            out.print_ok(&format!("Profile '{name}' URL: {} → {}", old.url, profile.url));
+            cred_file.profiles.insert(name.clone(), profile);
+            cred_file.save()?;
+            Ok(())
+        }
+        ProfileAction::RotateToken { name } => {
+            let name = name.clone().or_else(|| current_profile(cli_profile, &cred_file)).context(
+                "沒有使用中的 profile，請指定名稱",
+            )?;
+            rotate_token(out, &mut cred_file, &name, cli_token).await
+        }
+        ProfileAction::Verify => verify(out, &cred_file).await,
+    }
+}
+
+/// 目前使用中的 profile：--profile > 本地 .redmine > 全域預設
+fn current_profile(cli_profile: Option<&str>, cred_file: &CredentialFile) -> Option<String> {
+    cli_profile
+        .map(String::from)
+        .or_else(|| LocalConfig::find().map(|(_, local)| local.profile))
+        .or_else(|| cred_file.default_profile.clone())
+}
+
+fn get<'a>(cred_file: &'a CredentialFile, name: &str) -> anyhow::Result<&'a Profile> {
+    cred_file
+        .get_profile(name)
+        .with_context(|| format!("Profile '{name}' 不存在"))
+}
+
+/// 認證方式與 secret 存放位置的簡短描述
+fn auth_label(profile: &Profile) -> String {
+    let auth = match &profile.login {
+        Some(login) => format!("basic ({login})"),
+        None => "token".into(),
+    };
+    match profile.backend() {
+        Some(backend) => format!("{auth}, {backend}"),
+        None => format!("{auth}, command"),
+    }
+}
+
+/// 去除 secret 的 profile 設定（JSON 輸出用）
+fn summary(name: &str, profile: &Profile, cred_file: &CredentialFile) -> anyhow::Result<Value> {
+    let mut value = serde_json::to_value(profile)?;
+    if let Some(map) = value.as_object_mut() {
+        for key in ["token", "password", "encrypted_token", "client_cert_password"] {
+            map.remove(key);
+        }
+        map.insert("name".into(), json!(name));
+        map.insert(
+            "default".into(),
+            json!(cred_file.default_profile.as_deref() == Some(name)),
+        );
+        map.insert(
+            "secret_backend".into(),
+            json!(profile.backend().map(|b| b.to_string()).unwrap_or_else(|| "command".into())),
+        );
+    }
+    Ok(value)
+}
+
+fn list(out: &Output, cred_file: &CredentialFile) -> anyhow::Result<()> {
+    let local = LocalConfig::find().map(|(_, local)| local.profile);
+    let mut names: Vec<_> = cred_file.profiles.keys().collect();
+    names.sort();
+
+    let mut rows = Vec::new();
+    let mut items = Vec::new();
+    for name in names {
+        let p = &cred_file.profiles[name];
+        let mut markers = Vec::new();
+        if cred_file.default_profile.as_deref() == Some(name.as_str()) {
+            markers.push("default");
+        }
+        if local.as_deref() == Some(name.as_str()) {
+            markers.push("local");
+        }
+        rows.push(vec![
+            name.clone(),
+            p.url.clone(),
+            auth_label(p),
+            p.switch_user.clone().unwrap_or_default(),
+            p.connection.proxy.clone().unwrap_or_default(),
+            markers.join(", "),
+        ]);
+        items.push(summary(name, p, cred_file)?);
+    }
+    if rows.is_empty() && !out.json {
+        println!("尚無 profile，請用 `redmine login -g` 建立");
+        return Ok(());
+    }
+    out.print_table(
+        &["Name", "URL", "Auth", "Switch User", "Proxy", ""],
+        rows,
+        &Value::Array(items),
+    );
+    Ok(())
+}
+
+fn show(out: &Output, cred_file: &CredentialFile, name: &str) -> anyhow::Result<()> {
+    let p = get(cred_file, name)?;
+    let path = |p: &Option<std::path::PathBuf>| p.as_ref().map(|p| p.display().to_string());
+    let is_default = cred_file.default_profile.as_deref() == Some(name);
+
+    let mut pairs: Vec<(&str, String)> = vec![
+        ("Profile", format!("{name}{}", if is_default { " (default)" } else { "" })),
+        ("URL", p.url.clone()),
+        ("Auth", auth_label(p)),
+    ];
+    let optional = [
+        ("Token Command", p.token_command.clone()),
+        ("Switch User", p.switch_user.clone()),
+        ("Proxy", p.connection.proxy.clone()),
+        ("CA Cert", path(&p.connection.ca_cert)),
+        ("Client Cert", path(&p.connection.client_cert)),
+        ("Client Key", path(&p.connection.client_key)),
+        ("User Agent", p.connection.user_agent.clone()),
+        ("Insecure", p.connection.insecure.map(|v| if v { "yes" } else { "no" }.into())),
+    ];
+    pairs.extend(optional.into_iter().filter_map(|(k, v)| v.map(|v| (k, v))));
+    out.print_detail(&pairs, &summary(name, p, cred_file)?);
+    Ok(())
+}
+
+fn rename(out: &Output, cred_file: &mut CredentialFile, old: &str, new: &str) -> anyhow::Result<()> {
+    if cred_file.profiles.contains_key(new) {
+        bail!("Profile '{new}' 已存在");
+    }
+    let profile = cred_file
+        .profiles
+        .remove(old)
+        .with_context(|| format!("Profile '{old}' 不存在"))?;
+    profile.copy_helper_secret(old, &profile, new)?;
+    profile.erase_secret(old)?;
+    cred_file.profiles.insert(new.to_string(), profile);
+    if cred_file.default_profile.as_deref() == Some(old) {
+        cred_file.default_profile = Some(new.to_string());
+    }
+    cred_file.save()?;
+
+    let mut msg = format!("已將 profile '{old}' 重新命名為 '{new}'");
+    if let Some((path, mut local)) = LocalConfig::find().filter(|(_, l)| l.profile == old) {
+        local.profile = new.to_string();
+        local.save(&path)?;
+        msg.push_str(&format!("\n已更新本地 .redmine ({})", path.display()));
+    }
+    out.print_ok(&msg);
+    Ok(())
+}
+
+async fn rotate_token(
+    out: &Output,
+    cred_file: &mut CredentialFile,
+    name: &str,
+    cli_token: Option<&str>,
+) -> anyhow::Result<()> {
+    let profile = get(cred_file, name)?;
+    let Some(backend) = profile.backend() else {
+        bail!("Profile '{name}' 使用 token_command 取得 token，請直接更換來源");
+    };
+    let value = match cli_token {
+        Some(token) => token.to_string(),
+        None if profile.login.is_some() => secret::prompt_hidden("New Password: ")?,
+        None => secret::prompt_hidden("New API Token: ")?,
+    };
+
+    // 先以新 token 驗證連線，再寫回原本的後端
+    let mut candidate = profile.clone();
+    candidate.store_secret(name, &value, &Default::default())?;
+    let mut config = Config::from_resolved(&candidate.resolve(name, CredentialSource::CliFlags)?)?;
+    config.switch_user = None;
+    let user = config.client_builder().build()?.get_current_user().await?.user;
+
+    let mut profile = profile.clone();
+    profile.store_secret(name, &value, &backend)?;
+    cred_file.profiles.insert(name.to_string(), profile);
+    cred_file.save()?;
+    out.print_ok(&format!(
+        "已更換 profile '{name}' 的 token（{} {} ({})，{backend}）",
+        user.firstname, user.lastname, user.login
+    ));
+    Ok(())
+}
+
+async fn verify(out: &Output, cred_file: &CredentialFile) -> anyhow::Result<()> {
+    let mut names: Vec<_> = cred_file.profiles.keys().cloned().collect();
+    names.sort();
+
+    // secret 依序取得（加密後端可能需要在終端機上輸入密語），連線檢查並行
+    let checks = names.iter().map(|name| {
+        let client = cred_file.profiles[name]
+            .resolve(name, CredentialSource::GlobalProfile { profile_name: name.clone() })
+            .and_then(|resolved| Config::from_resolved(&resolved))
+            .and_then(|config| config.client_builder().build());
+        async move {
+            match client {
+                Ok(client) => client.get_current_user().await.map(|resp| resp.user),
+                Err(e) => Err(e),
+            }
+        }
+    });
+    let results = futures::future::join_all(checks).await;
+
+    let mut rows = Vec::new();
+    let mut items = Vec::new();
+    let mut failed = 0;
+    for (name, result) in names.iter().zip(results) {
+        let url = &cred_file.profiles[name].url;
+        let (ok, status, user) = match result {
+            Ok(u) => (true, "ok".to_string(), format!("{} {} ({})", u.firstname, u.lastname, u.login)),
+            Err(e) => {
+                failed += 1;
+                (false, format!("error: {e}"), String::new())
+            }
+        };
+        items.push(json!({ "name": name, "url": url, "ok": ok, "status": status, "user": user }));
+        rows.push(vec![name.clone(), url.clone(), status, user]);
+    }
+    out.print_table(&["Profile", "URL", "Status", "User"], rows, &Value::Array(items));
+
+    if failed > 0 {
+        bail!("{failed} 個 profile 無法連線");
+    }
+    Ok(())
+}
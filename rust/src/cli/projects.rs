@@ -7,8 +7,8 @@ use crate::RedmineClient;
 
 pub async fn run(client: &RedmineClient, out: &Output, action: ProjectsAction) -> anyhow::Result<()> {
     match action {
-        ProjectsAction::List => {
-            let resp = client.get_projects().await.context("取得專案列表失敗")?;
+        ProjectsAction::List { max_items } => {
+            let resp = client.get_projects(max_items).await.context("取得專案列表失敗")?;
             let rows: Vec<Vec<String>> = resp
                 .projects
                 .iter()
@@ -27,8 +27,8 @@ pub async fn run(client: &RedmineClient, out: &Output, action: ProjectsAction) -
                 &serde_json::to_value(&resp)?,
             );
         }
-        ProjectsAction::Members { project_id } => {
-            let resp = client.get_project_members(&project_id).await
+        ProjectsAction::Members { project_id, max_items } => {
+            let resp = client.get_project_members(&project_id, max_items).await
                 .with_context(|| format!("取得專案 {project_id} 成員失敗"))?;
             let rows: Vec<Vec<String>> = resp
                 .memberships
@@ -41,6 +41,15 @@ pub async fn run(client: &RedmineClient, out: &Output, action: ProjectsAction) -
                 .collect();
             out.print_table(&["ID", "User", "Roles"], rows, &serde_json::to_value(&resp)?);
         }
+        ProjectsAction::Repositories { project_id } => {
+            let repositories = client.get_project_repositories(&project_id).await
+                .with_context(|| format!("取得專案 {project_id} 版本庫失敗"))?;
+            let rows: Vec<Vec<String>> = repositories
+                .iter()
+                .map(|r| vec![r.id.to_string(), r.url.clone().unwrap_or_default()])
+                .collect();
+            out.print_table(&["ID", "URL"], rows, &serde_json::to_value(&repositories)?);
+        }
     }
     Ok(())
 }
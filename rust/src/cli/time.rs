@@ -14,7 +14,17 @@ pub async fn run(client: &RedmineClient, out: &Output, action: TimeAction) -> an
             from,
             to,
             limit,
+            all,
+            max_items,
         } => {
+            let project_id = match project_id {
+                Some(p) => Some(client.resolve_project(&p).await?),
+                None => None,
+            };
+            let user_id = match user_id {
+                Some(u) => Some(client.resolve_user_filter(&u, project_id.as_deref()).await?),
+                None => None,
+            };
             let params = TimeEntryListParams {
                 project_id,
                 user_id,
@@ -23,7 +33,11 @@ pub async fn run(client: &RedmineClient, out: &Output, action: TimeAction) -> an
                 limit: Some(limit),
                 ..Default::default()
             };
-            let resp = client.get_time_entries(&params).await.context("取得工時列表失敗")?;
+            let resp = match super::fetch_all_limit(all, max_items) {
+                Some(max_items) => client.get_all_time_entries(&params, Some(max_items)).await,
+                None => client.get_time_entries(&params).await,
+            }
+            .context("取得工時列表失敗")?;
             let rows: Vec<Vec<String>> = resp
                 .time_entries
                 .iter()
@@ -54,6 +68,14 @@ pub async fn run(client: &RedmineClient, out: &Output, action: TimeAction) -> an
             comments,
             spent_on,
         } => {
+            let project_id = match project_id {
+                Some(p) => Some(client.resolve_project(&p).await?),
+                None => None,
+            };
+            let activity_id = match activity_id {
+                Some(a) => Some(client.resolve_activity(&a).await?),
+                None => None,
+            };
             let params = TimeEntryCreateParams {
                 hours,
                 issue_id,
@@ -8,14 +8,22 @@ use crate::RedmineClient;
 
 pub async fn run(client: &RedmineClient, out: &Output, action: UsersAction) -> anyhow::Result<()> {
     match action {
-        UsersAction::List { name, group_id, limit } => {
+        UsersAction::List { name, group_id, limit, all, max_items } => {
+            let group_id = match group_id {
+                Some(g) => Some(client.resolve_group(&g).await?),
+                None => None,
+            };
             let params = UserListParams {
                 name,
                 group_id,
                 limit: Some(limit),
                 ..Default::default()
             };
-            let resp = client.get_users(&params).await.context("取得使用者列表失敗")?;
+            let resp = match super::fetch_all_limit(all, max_items) {
+                Some(max_items) => client.get_all_users(&params, Some(max_items)).await,
+                None => client.get_users(&params).await,
+            }
+            .context("取得使用者列表失敗")?;
             let rows: Vec<Vec<String>> = resp
                 .users
                 .iter()
@@ -41,9 +49,92 @@ pub async fn run(client: &RedmineClient, out: &Output, action: UsersAction) -> a
                 ("Email", u.mail.clone().unwrap_or("-".into())),
                 ("Created", u.created_on.clone()),
                 ("Last Login", u.last_login_on.clone().unwrap_or("-".into())),
+                ("Status", user_status_name(u.status).into()),
             ];
             out.print_detail(&pairs, &serde_json::to_value(&resp)?);
         }
+        UsersAction::Create {
+            login,
+            firstname,
+            lastname,
+            mail,
+            password,
+            generate_password,
+            must_change_password,
+            auth_source_id,
+            admin,
+        } => {
+            let params = UserCreateParams {
+                login,
+                firstname,
+                lastname,
+                mail,
+                password,
+                generate_password: generate_password.then_some(true),
+                must_change_passwd: must_change_password.then_some(true),
+                auth_source_id,
+                admin: admin.then_some(true),
+                ..Default::default()
+            };
+            let resp = client.create_user(&params).await.context("建立使用者失敗")?;
+            out.print_ok(&format!("使用者 {} 已建立 (ID: {})", resp.user.login, resp.user.id));
+        }
+        UsersAction::Update {
+            id,
+            login,
+            firstname,
+            lastname,
+            mail,
+            password,
+            auth_source_id,
+            admin,
+        } => {
+            let params = UserUpdateParams {
+                login,
+                firstname,
+                lastname,
+                mail,
+                password,
+                auth_source_id,
+                admin,
+                ..Default::default()
+            };
+            client.update_user(id, &params).await
+                .with_context(|| format!("更新使用者 #{id} 失敗"))?;
+            out.print_ok(&format!("使用者 #{id} 已更新"));
+        }
+        UsersAction::Lock { id } => {
+            let params = UserUpdateParams {
+                status: Some(USER_STATUS_LOCKED),
+                ..Default::default()
+            };
+            client.update_user(id, &params).await
+                .with_context(|| format!("鎖定使用者 #{id} 失敗"))?;
+            out.print_ok(&format!("使用者 #{id} 已鎖定"));
+        }
+        UsersAction::Unlock { id } => {
+            let params = UserUpdateParams {
+                status: Some(USER_STATUS_ACTIVE),
+                ..Default::default()
+            };
+            client.update_user(id, &params).await
+                .with_context(|| format!("解鎖使用者 #{id} 失敗"))?;
+            out.print_ok(&format!("使用者 #{id} 已解鎖"));
+        }
+        UsersAction::Rm { id } => {
+            client.delete_user(id).await
+                .with_context(|| format!("刪除使用者 #{id} 失敗"))?;
+            out.print_ok(&format!("使用者 #{id} 已刪除"));
+        }
     }
     Ok(())
 }
+
+fn user_status_name(status: Option<u8>) -> &'static str {
+    match status {
+        Some(USER_STATUS_ACTIVE) => "active",
+        Some(USER_STATUS_REGISTERED) => "registered",
+        Some(USER_STATUS_LOCKED) => "locked",
+        _ => "-",
+    }
+}
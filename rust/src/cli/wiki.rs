@@ -3,8 +3,11 @@
 use anyhow::Context;
 use crate::cli::app::WikiAction;
 use crate::cli::output::Output;
+use crate::cli::wiki_sync;
 use crate::client::*;
+use crate::markup;
 use crate::RedmineClient;
+use std::path::Path;
 
 pub async fn run(client: &RedmineClient, out: &Output, action: WikiAction) -> anyhow::Result<()> {
     match action {
@@ -17,16 +20,23 @@ pub async fn run(client: &RedmineClient, out: &Output, action: WikiAction) -> an
                 .map(|p| {
                     vec![
                         p.title.clone(),
+                        p.parent.as_ref().map(|parent| parent.title.clone()).unwrap_or_default(),
                         p.version.to_string(),
                         p.updated_on.clone(),
                     ]
                 })
                 .collect();
-            out.print_table(&["Title", "Version", "Updated"], rows, &serde_json::to_value(&resp)?);
+            out.print_table(&["Title", "Parent", "Version", "Updated"], rows, &serde_json::to_value(&resp)?);
         }
-        WikiAction::Show { project_id, title } => {
-            let resp = client.get_wiki_page(&project_id, &title).await
-                .with_context(|| format!("取得 Wiki {project_id}/{title} 失敗"))?;
+        WikiAction::Show { project_id, title, version, markdown } => {
+            let mut resp = match version {
+                Some(v) => client.get_wiki_page_version(&project_id, &title, v).await,
+                None => client.get_wiki_page(&project_id, &title).await,
+            }
+            .with_context(|| format!("取得 Wiki {project_id}/{title} 失敗"))?;
+            if markdown {
+                resp.wiki_page.text = markup::server_to_markdown(&resp.wiki_page.text, client.text_format());
+            }
             if out.json {
                 out.print_json(&serde_json::to_value(&resp)?);
             } else {
@@ -35,12 +45,43 @@ pub async fn run(client: &RedmineClient, out: &Output, action: WikiAction) -> an
                 println!("{}", p.text);
             }
         }
+        WikiAction::History { project_id, title, limit } => {
+            let resp = client.get_wiki_page_history(&project_id, &title, limit).await
+                .with_context(|| format!("取得 Wiki {project_id}/{title} 歷史失敗"))?;
+            let rows: Vec<Vec<String>> = resp
+                .versions
+                .iter()
+                .map(|v| {
+                    vec![
+                        v.version.to_string(),
+                        v.author.name.clone(),
+                        v.updated_on.clone(),
+                        v.comments.clone().unwrap_or_default(),
+                    ]
+                })
+                .collect();
+            out.print_table(&["Version", "Author", "Updated", "Comment"], rows, &serde_json::to_value(&resp)?);
+        }
+        WikiAction::Diff { project_id, title, from, to } => {
+            let resp = client.diff_wiki_page(&project_id, &title, from, to).await
+                .with_context(|| format!("比較 Wiki {project_id}/{title} 失敗"))?;
+            if out.json {
+                out.print_json(&serde_json::to_value(&resp)?);
+            } else if resp.diff.is_empty() {
+                println!("v{} 與 v{} 內容相同", resp.from, resp.to);
+            } else {
+                print!("{}", resp.diff);
+            }
+        }
         WikiAction::Update {
             project_id,
             title,
             text,
             file,
             comments,
+            version,
+            parent,
+            markdown,
         } => {
             let content = if let Some(f) = file {
                 tokio::fs::read_to_string(&f).await
@@ -50,11 +91,55 @@ pub async fn run(client: &RedmineClient, out: &Output, action: WikiAction) -> an
             } else {
                 anyhow::bail!("請提供 --text 或 --file");
             };
-            let params = WikiPageParams { text: content, comments };
+            let content = if markdown {
+                markup::markdown_to_server(&content, client.text_format())
+            } else {
+                content
+            };
+            let params = WikiPageParams {
+                text: content,
+                comments,
+                version,
+                parent_title: parent,
+            };
             client.update_wiki_page(&project_id, &title, &params).await
                 .with_context(|| format!("更新 Wiki {project_id}/{title} 失敗"))?;
             out.print_ok(&format!("Wiki {project_id}/{title} 已更新"));
         }
+        WikiAction::Rename { project_id, title, new_title, delete_old } => {
+            let result = client.rename_wiki_page(&project_id, &title, &new_title, delete_old).await
+                .with_context(|| format!("重新命名 Wiki {project_id}/{title} 失敗"))?;
+            if out.json {
+                out.print_json(&serde_json::to_value(&result)?);
+            } else {
+                println!("Wiki {project_id}/{title} → {new_title}");
+                for child in &result.moved_children {
+                    println!("  子頁面 {child} 已改掛");
+                }
+                for failure in &result.failed_children {
+                    println!("  子頁面 {} 改掛失敗: {}", failure.title, failure.error);
+                }
+                if result.old_page_deleted {
+                    println!("舊頁面 {title} 已刪除");
+                } else {
+                    println!("舊頁面 {title} 保留（內容改為指向 {new_title}）");
+                }
+            }
+            if !result.failed_children.is_empty() {
+                anyhow::bail!("{} 個子頁面改掛失敗，舊頁面未刪除", result.failed_children.len());
+            }
+        }
+        WikiAction::Delete { project_id, title } => {
+            client.delete_wiki_page(&project_id, &title).await
+                .with_context(|| format!("刪除 Wiki {project_id}/{title} 失敗"))?;
+            out.print_ok(&format!("Wiki {project_id}/{title} 已刪除"));
+        }
+        WikiAction::Pull { project_id, dir, ext, dry_run } => {
+            wiki_sync::pull(client, out, &project_id, Path::new(&dir), &ext, dry_run).await?;
+        }
+        WikiAction::Push { project_id, dir, ext, dry_run } => {
+            wiki_sync::push(client, out, &project_id, Path::new(&dir), &ext, dry_run).await?;
+        }
     }
     Ok(())
 }
@@ -0,0 +1,389 @@
+//! Wiki 雙向同步 — 本地目錄 ⇄ Redmine 專案 Wiki
+//!
+//! 每個頁面對應 `DIR/{title}.{ext}`，同步狀態記錄在 `DIR/.redmine-wiki.toml`：
+//! 上次同步時的頁面版本與本地內容雜湊。兩邊都有修改時回報衝突，不會覆蓋任何一方。
+//! 沒有 manifest（例如 CI 的全新 checkout）或版本已過期時，改以遠端內容雜湊比對，
+//! 兩邊內容相同即視為已同步。
+
+use anyhow::Context;
+use crate::cli::output::Output;
+use crate::client::*;
+use crate::error::RedmineError;
+use crate::RedmineClient;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+const MANIFEST_FILE: &str = ".redmine-wiki.toml";
+
+/// 同步狀態檔
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Manifest {
+    project: String,
+    #[serde(default)]
+    pages: BTreeMap<String, PageState>,
+}
+
+/// 單一頁面的上次同步狀態
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PageState {
+    version: u64,
+    hash: String,
+}
+
+impl Manifest {
+    fn load(dir: &Path, project_id: &str) -> anyhow::Result<Self> {
+        let path = dir.join(MANIFEST_FILE);
+        if !path.exists() {
+            return Ok(Self {
+                project: project_id.to_string(),
+                ..Default::default()
+            });
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("讀取 {} 失敗", path.display()))?;
+        let manifest: Self = toml::from_str(&content)
+            .with_context(|| format!("解析 {} 失敗", path.display()))?;
+        if manifest.project != project_id {
+            anyhow::bail!(
+                "{} 屬於專案 '{}'，與指定的 '{project_id}' 不符",
+                path.display(),
+                manifest.project
+            );
+        }
+        Ok(manifest)
+    }
+
+    fn save(&self, dir: &Path) -> anyhow::Result<()> {
+        let content = toml::to_string_pretty(self)?;
+        std::fs::write(dir.join(MANIFEST_FILE), content)?;
+        Ok(())
+    }
+}
+
+/// 單一頁面的同步結果
+struct SyncRow {
+    title: String,
+    action: &'static str,
+    detail: String,
+}
+
+impl SyncRow {
+    fn new(title: &str, action: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            title: title.to_string(),
+            action,
+            detail: detail.into(),
+        }
+    }
+}
+
+fn content_hash(content: &str) -> String {
+    format!("{:x}", Sha256::digest(content.as_bytes()))
+}
+
+/// 單一頁面三方比對的結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Change {
+    /// 兩邊內容相同
+    None,
+    /// 只有本地修改
+    Local,
+    /// 只有遠端修改
+    Remote,
+    /// 兩邊皆有修改且內容不同
+    Conflict,
+}
+
+/// 以上次同步的雜湊為基準比對本地與遠端（`None` 表示不存在）
+fn classify(base: Option<&str>, local: Option<&str>, remote: Option<&str>) -> Change {
+    if local == remote {
+        return Change::None;
+    }
+    match (local != base, remote != base) {
+        (true, true) => Change::Conflict,
+        (true, false) => Change::Local,
+        _ => Change::Remote,
+    }
+}
+
+/// 遠端內容雜湊：版本與 manifest 相同時沿用記錄的雜湊，否則依實際內容計算
+fn remote_hash(state: Option<&PageState>, page: &WikiPage) -> String {
+    match state {
+        Some(s) if s.version == page.version => s.hash.clone(),
+        _ => content_hash(&page.text),
+    }
+}
+
+fn page_path(dir: &Path, title: &str, ext: &str) -> PathBuf {
+    dir.join(format!("{title}.{ext}"))
+}
+
+/// 下載 Wiki 頁面到本地目錄
+pub async fn pull(
+    client: &RedmineClient,
+    out: &Output,
+    project_id: &str,
+    dir: &Path,
+    ext: &str,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    std::fs::create_dir_all(dir).with_context(|| format!("建立目錄 {} 失敗", dir.display()))?;
+    let mut manifest = Manifest::load(dir, project_id)?;
+    let pages = client.get_wiki_pages(project_id).await
+        .with_context(|| format!("取得 Wiki 列表失敗 (project: {project_id})"))?;
+
+    let mut rows = Vec::new();
+    for summary in &pages.wiki_pages {
+        let title = summary.title.as_str();
+        let path = page_path(dir, title, ext);
+        let state = manifest.pages.get(title).cloned();
+        let local = std::fs::read_to_string(&path).ok().map(|content| content_hash(&content));
+
+        // 本地沒有檔案時一律下載；版本未變時不必取得遠端內容
+        let up_to_date = state.as_ref().is_some_and(|s| s.version == summary.version);
+        if up_to_date && local.is_some() {
+            let action = if local.as_deref() == state.as_ref().map(|s| s.hash.as_str()) {
+                "up-to-date"
+            } else {
+                "local-changes"
+            };
+            rows.push(SyncRow::new(title, action, format!("v{}", summary.version)));
+            continue;
+        }
+
+        let page = client.get_wiki_page(project_id, title).await
+            .with_context(|| format!("取得 Wiki {project_id}/{title} 失敗"))?
+            .wiki_page;
+        let remote = remote_hash(state.as_ref(), &page);
+        let base = state.as_ref().map(|s| s.hash.as_str());
+        let change = match &local {
+            Some(local) => classify(base, Some(local), Some(&remote)),
+            None => Change::Remote,
+        };
+        match change {
+            Change::None => {
+                // 內容已一致，只補記 manifest
+                if !dry_run {
+                    manifest.pages.insert(title.to_string(), PageState { version: page.version, hash: remote });
+                    manifest.save(dir)?;
+                }
+                rows.push(SyncRow::new(title, "up-to-date", format!("v{}", page.version)));
+                continue;
+            }
+            Change::Local => {
+                rows.push(SyncRow::new(title, "local-changes", format!("v{}", page.version)));
+                continue;
+            }
+            Change::Conflict => {
+                rows.push(SyncRow::new(
+                    title,
+                    "conflict",
+                    format!("本地與遠端 (v{}) 皆有修改", page.version),
+                ));
+                continue;
+            }
+            Change::Remote => {}
+        }
+        if dry_run {
+            rows.push(SyncRow::new(title, "would-pull", format!("v{}", page.version)));
+            continue;
+        }
+
+        std::fs::write(&path, &page.text)
+            .with_context(|| format!("寫入 {} 失敗", path.display()))?;
+        manifest.pages.insert(
+            title.to_string(),
+            PageState {
+                version: page.version,
+                hash: content_hash(&page.text),
+            },
+        );
+        // 每頁寫入後立即更新 manifest，中途失敗時已同步的頁面仍有記錄
+        manifest.save(dir)?;
+        rows.push(SyncRow::new(title, "pulled", format!("v{}", page.version)));
+    }
+
+    report(out, rows)
+}
+
+/// 上傳本地目錄中有修改的頁面
+pub async fn push(
+    client: &RedmineClient,
+    out: &Output,
+    project_id: &str,
+    dir: &Path,
+    ext: &str,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let mut manifest = Manifest::load(dir, project_id)?;
+
+    let mut files: Vec<(String, PathBuf)> = std::fs::read_dir(dir)
+        .with_context(|| format!("讀取目錄 {} 失敗", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.is_file() && p.extension().is_some_and(|e| e == ext))
+        .filter_map(|p| {
+            let title = p.file_stem()?.to_str()?.to_string();
+            Some((title, p))
+        })
+        .collect();
+    files.sort();
+
+    let mut rows = Vec::new();
+    for (title, path) in &files {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("讀取 {} 失敗", path.display()))?;
+        let hash = content_hash(&content);
+        let state = manifest.pages.get(title).cloned();
+        if state.as_ref().is_some_and(|s| s.hash == hash) {
+            rows.push(SyncRow::new(title, "unchanged", ""));
+            continue;
+        }
+
+        let remote = match client.get_wiki_page(project_id, title).await {
+            Ok(resp) => Some(resp.wiki_page),
+            Err(RedmineError::NotFound { .. }) => None,
+            Err(e) => {
+                return Err(e).with_context(|| format!("取得 Wiki {project_id}/{title} 失敗"));
+            }
+        };
+        let base = state.as_ref().map(|s| s.hash.as_str());
+        let remote_hash = remote.as_ref().map(|page| remote_hash(state.as_ref(), page));
+        match classify(base, Some(&hash), remote_hash.as_deref()) {
+            Change::None => {
+                // 內容已一致（例如 manifest 未提交回版本庫），只補記 manifest
+                let version = remote.as_ref().map_or(0, |page| page.version);
+                if !dry_run {
+                    manifest.pages.insert(title.clone(), PageState { version, hash });
+                    manifest.save(dir)?;
+                }
+                rows.push(SyncRow::new(title, "unchanged", format!("v{version}")));
+                continue;
+            }
+            Change::Remote => {
+                rows.push(SyncRow::new(title, "remote-changes", "請先 pull"));
+                continue;
+            }
+            Change::Conflict => {
+                let detail = match &remote {
+                    Some(page) => format!("本地與遠端 (v{}) 皆有修改", page.version),
+                    None => "遠端頁面已被刪除".into(),
+                };
+                rows.push(SyncRow::new(title, "conflict", detail));
+                continue;
+            }
+            Change::Local => {}
+        }
+
+        let action = if remote.is_some() { "pushed" } else { "created" };
+        if dry_run {
+            rows.push(SyncRow::new(title, action, "(dry-run)"));
+            continue;
+        }
+
+        let params = WikiPageParams {
+            text: content,
+            comments: None,
+            // 遠端內容與基準相同，以目前版本做樂觀鎖
+            version: remote.as_ref().map(|page| page.version),
+            parent_title: None,
+        };
+        match client.update_wiki_page(project_id, title, &params).await {
+            Ok(()) => {}
+            Err(RedmineError::Conflict { .. }) => {
+                rows.push(SyncRow::new(title, "conflict", "上傳時遠端已被修改"));
+                continue;
+            }
+            Err(e) => {
+                return Err(e).with_context(|| format!("更新 Wiki {project_id}/{title} 失敗"));
+            }
+        }
+
+        let page = client.get_wiki_page(project_id, title).await
+            .with_context(|| format!("取得 Wiki {project_id}/{title} 失敗"))?
+            .wiki_page;
+        manifest.pages.insert(
+            title.clone(),
+            PageState {
+                version: page.version,
+                hash,
+            },
+        );
+        manifest.save(dir)?;
+        rows.push(SyncRow::new(title, action, format!("v{}", page.version)));
+    }
+
+    report(out, rows)
+}
+
+/// 輸出同步結果；有衝突時回傳錯誤（CI 可據此失敗）
+fn report(out: &Output, rows: Vec<SyncRow>) -> anyhow::Result<()> {
+    let conflicts = rows.iter().filter(|r| r.action == "conflict").count();
+    let json_value: Vec<serde_json::Value> = rows
+        .iter()
+        .map(|r| serde_json::json!({ "title": r.title, "action": r.action, "detail": r.detail }))
+        .collect();
+    let table_rows: Vec<Vec<String>> = rows
+        .into_iter()
+        .map(|r| vec![r.title, r.action.to_string(), r.detail])
+        .collect();
+    out.print_table(&["Title", "Action", "Detail"], table_rows, &serde_json::Value::Array(json_value));
+
+    if conflicts > 0 {
+        anyhow::bail!("{conflicts} 個頁面有衝突，未同步");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Hash = Option<&'static str>;
+
+    /// (base, local, remote, 預期結果)
+    const CASES: &[(Hash, Hash, Hash, Change)] = &[
+        // 有 manifest
+        (Some("a"), Some("a"), Some("a"), Change::None),
+        (Some("a"), Some("b"), Some("a"), Change::Local),
+        (Some("a"), Some("a"), Some("b"), Change::Remote),
+        (Some("a"), Some("b"), Some("c"), Change::Conflict),
+        (Some("a"), Some("b"), Some("b"), Change::None),
+        // 遠端已刪除
+        (Some("a"), Some("a"), None, Change::Remote),
+        (Some("a"), Some("b"), None, Change::Conflict),
+        // 沒有 manifest（全新 checkout）
+        (None, Some("a"), Some("a"), Change::None),
+        (None, Some("a"), Some("b"), Change::Conflict),
+        (None, Some("a"), None, Change::Local),
+        (None, None, Some("a"), Change::Remote),
+    ];
+
+    #[test]
+    fn classify_decision_table() {
+        for &(base, local, remote, expected) in CASES {
+            assert_eq!(classify(base, local, remote), expected, "base={base:?} local={local:?} remote={remote:?}");
+        }
+    }
+
+    #[test]
+    fn stale_manifest_uses_remote_content() {
+        let state = PageState { version: 3, hash: content_hash("old") };
+        let page = |version, text: &str| WikiPage {
+            title: "Design".into(),
+            parent: None,
+            text: text.into(),
+            version,
+            author: IdName { id: 1, name: "Admin".into() },
+            comments: None,
+            created_on: String::new(),
+            updated_on: String::new(),
+        };
+        // 版本相同時沿用 manifest 的雜湊
+        assert_eq!(remote_hash(Some(&state), &page(3, "ignored")), state.hash);
+        // 版本已過期但內容未變：遠端視為未修改
+        let remote = remote_hash(Some(&state), &page(4, "old"));
+        assert_eq!(classify(Some(&state.hash), Some(&content_hash("new")), Some(&remote)), Change::Local);
+    }
+}
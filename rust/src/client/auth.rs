@@ -0,0 +1,60 @@
+//! 認證方式 — API key、HTTP Basic，以及由外部命令取得 secret
+
+use super::*;
+use base64::Engine;
+
+/// Redmine 認證方式
+#[derive(Clone)]
+pub enum Auth {
+    /// `X-Redmine-API-Key` 標頭（預設）
+    ApiKey(String),
+    /// HTTP Basic（伺服器停用 REST API key 時）
+    Basic { login: String, password: String },
+}
+
+impl Auth {
+    /// 對應的 HTTP 標頭
+    pub(super) fn header(&self) -> Result<(header::HeaderName, header::HeaderValue)> {
+        let (name, value) = match self {
+            Self::ApiKey(key) => (header::HeaderName::from_static("x-redmine-api-key"), key.clone()),
+            Self::Basic { login, password } => {
+                let encoded = base64::engine::general_purpose::STANDARD
+                    .encode(format!("{login}:{password}"));
+                (header::AUTHORIZATION, format!("Basic {encoded}"))
+            }
+        };
+        let mut value = header::HeaderValue::from_str(&value)
+            .map_err(|_| RedmineError::Config("無效的 API Key 或密碼".into()))?;
+        value.set_sensitive(true);
+        Ok((name, value))
+    }
+}
+
+impl std::fmt::Debug for Auth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ApiKey(_) => write!(f, "ApiKey(***)"),
+            Self::Basic { login, .. } => write!(f, "Basic({login})"),
+        }
+    }
+}
+
+/// 執行外部命令（如 `op read ...`、`pass show ...`）取得 secret，回傳去除前後空白的 stdout
+pub fn run_secret_command(command: &str) -> Result<String> {
+    let output = crate::shell::command(command)
+        .stdin(std::process::Stdio::null())
+        .stderr(std::process::Stdio::inherit())
+        .output()
+        .map_err(|e| RedmineError::Config(format!("無法執行 token_command: {e}")))?;
+    if !output.status.success() {
+        return Err(RedmineError::Config(format!(
+            "token_command 執行失敗 ({})",
+            output.status
+        )));
+    }
+    let secret = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if secret.is_empty() {
+        return Err(RedmineError::Config("token_command 沒有輸出".into()));
+    }
+    Ok(secret)
+}
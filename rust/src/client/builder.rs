@@ -0,0 +1,283 @@
+//! RedmineClient 建構器 — 認證、逾時、User-Agent、代理與 TLS 設定
+//!
+//! 連線設定可寫在 credentials.toml 的 profile 中（見 [`ConnectionOptions`]），
+//! 也可用環境變數覆寫：
+//!
+//! | 環境變數 | 說明 |
+//! |----------|------|
+//! | `REDMINE_PROXY` | HTTP(S) 代理 URL |
+//! | `REDMINE_CA_CERT` | 額外信任的根憑證（PEM，可含多張） |
+//! | `REDMINE_CLIENT_CERT` | mTLS 用戶端憑證（PEM 或 PKCS#12 `.p12`/`.pfx`） |
+//! | `REDMINE_CLIENT_KEY` | 用戶端私鑰（PKCS#8 PEM，PEM 憑證時必填） |
+//! | `REDMINE_CLIENT_CERT_PASSWORD` | PKCS#12 密碼 |
+//! | `REDMINE_INSECURE` | `1` 時不驗證伺服器憑證（僅限測試環境），`0` 可覆寫 profile 的設定 |
+//! | `REDMINE_USER_AGENT` | 自訂 User-Agent |
+
+use super::*;
+use reqwest::{Certificate, Identity, Proxy};
+use serde::Deserialize;
+use std::path::PathBuf;
+use tracing::warn;
+
+/// 預設 User-Agent
+pub const DEFAULT_USER_AGENT: &str = "MitakeRedmineMCP/1.0";
+
+/// 代理與 TLS 連線設定（credentials.toml profile 欄位）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConnectionOptions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_cert: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_cert: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_key: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_cert_password: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insecure: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+}
+
+impl ConnectionOptions {
+    /// 從 `REDMINE_PROXY` 等環境變數讀取
+    pub fn from_env() -> Self {
+        let var = |key: &str| std::env::var(key).ok().filter(|v| !v.is_empty());
+        Self {
+            proxy: var("REDMINE_PROXY"),
+            ca_cert: var("REDMINE_CA_CERT").map(PathBuf::from),
+            client_cert: var("REDMINE_CLIENT_CERT").map(PathBuf::from),
+            client_key: var("REDMINE_CLIENT_KEY").map(PathBuf::from),
+            client_cert_password: var("REDMINE_CLIENT_CERT_PASSWORD"),
+            insecure: var("REDMINE_INSECURE").map(|v| v == "1" || v.eq_ignore_ascii_case("true")),
+            user_agent: var("REDMINE_USER_AGENT"),
+        }
+    }
+
+    /// 以 `other` 中有設定的欄位覆寫
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            proxy: other.proxy.or(self.proxy),
+            ca_cert: other.ca_cert.or(self.ca_cert),
+            client_cert: other.client_cert.or(self.client_cert),
+            client_key: other.client_key.or(self.client_key),
+            client_cert_password: other.client_cert_password.or(self.client_cert_password),
+            insecure: other.insecure.or(self.insecure),
+            user_agent: other.user_agent.or(self.user_agent),
+        }
+    }
+}
+
+/// [`RedmineClient`] 建構器
+#[derive(Clone)]
+pub struct RedmineClientBuilder {
+    base_url: String,
+    auth: Auth,
+    switch_user: Option<String>,
+    sys_api_key: Option<String>,
+    timeout: Duration,
+    connection: ConnectionOptions,
+    text_format: TextFormat,
+    cache: MetadataCache,
+    retry: RetryPolicy,
+}
+
+impl RedmineClientBuilder {
+    pub(super) fn new(base_url: &str, api_key: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            auth: Auth::ApiKey(api_key.to_string()),
+            switch_user: None,
+            sys_api_key: None,
+            timeout: DEFAULT_TIMEOUT,
+            connection: ConnectionOptions::default(),
+            text_format: TextFormat::default(),
+            cache: MetadataCache::default(),
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// 改用 HTTP Basic 認證（取代 API key）
+    pub fn basic_auth(mut self, login: &str, password: &str) -> Self {
+        self.auth = Auth::Basic {
+            login: login.to_string(),
+            password: password.to_string(),
+        };
+        self
+    }
+
+    /// 以指定登入帳號的身分呼叫（`X-Redmine-Switch-User`，需管理員認證）
+    pub fn switch_user(mut self, login: &str) -> Self {
+        self.switch_user = Some(login.to_string());
+        self
+    }
+
+    /// 版本庫管理 Web Service 的 API 金鑰（列出專案版本庫用）
+    pub fn sys_api_key(mut self, key: &str) -> Self {
+        self.sys_api_key = Some(key.to_string());
+        self
+    }
+
+    /// 請求逾時（預設 30 秒）
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// 自訂 User-Agent（預設 `MitakeRedmineMCP/1.0`）
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.connection.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// 經由 HTTP(S) 代理連線
+    pub fn proxy(mut self, url: &str) -> Self {
+        self.connection.proxy = Some(url.to_string());
+        self
+    }
+
+    /// 額外信任的根憑證（PEM bundle）
+    pub fn ca_cert(mut self, path: impl Into<PathBuf>) -> Self {
+        self.connection.ca_cert = Some(path.into());
+        self
+    }
+
+    /// mTLS 用戶端憑證：PEM 憑證搭配 PKCS#8 私鑰，或 PKCS#12 檔（`key` 為 None）
+    pub fn client_cert(mut self, cert: impl Into<PathBuf>, key: Option<PathBuf>) -> Self {
+        self.connection.client_cert = Some(cert.into());
+        self.connection.client_key = key;
+        self
+    }
+
+    /// PKCS#12 用戶端憑證密碼
+    pub fn client_cert_password(mut self, password: &str) -> Self {
+        self.connection.client_cert_password = Some(password.to_string());
+        self
+    }
+
+    /// 不驗證伺服器憑證與主機名稱（僅限測試環境）
+    pub fn danger_accept_invalid_certs(mut self, insecure: bool) -> Self {
+        self.connection.insecure = Some(insecure);
+        self
+    }
+
+    /// 套用 profile / 環境變數的連線設定（已設定的欄位會被覆寫）
+    pub fn connection(mut self, options: &ConnectionOptions) -> Self {
+        self.connection = std::mem::take(&mut self.connection).merge(options.clone());
+        self
+    }
+
+    /// 伺服器文字格式
+    pub fn text_format(mut self, text_format: TextFormat) -> Self {
+        self.text_format = text_format;
+        self
+    }
+
+    /// 中繼資料快取（預設只存在記憶體）
+    pub fn cache(mut self, cache: MetadataCache) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// 暫時性錯誤的重試策略
+    pub fn retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// 建立客戶端；憑證檔無法讀取或格式錯誤時回傳 `RedmineError::Config`
+    pub fn build(self) -> Result<RedmineClient> {
+        let mut headers = header::HeaderMap::new();
+        let (auth_name, auth_value) = self.auth.header()?;
+        headers.insert(auth_name, auth_value);
+        headers.insert(
+            header::CONTENT_TYPE,
+            header::HeaderValue::from_static("application/json"),
+        );
+
+        let options = &self.connection;
+        let mut builder = Client::builder()
+            .default_headers(headers)
+            .user_agent(options.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT))
+            .timeout(self.timeout);
+
+        if let Some(proxy) = &options.proxy {
+            let proxy = Proxy::all(proxy)
+                .map_err(|e| RedmineError::Config(format!("無效的代理 URL '{proxy}': {e}")))?;
+            builder = builder.proxy(proxy);
+        }
+        if let Some(path) = &options.ca_cert {
+            let certs = Certificate::from_pem_bundle(&read_file(path)?)
+                .map_err(|e| RedmineError::Config(format!("無法解析根憑證 {}: {e}", path.display())))?;
+            builder = builder.tls_certs_merge(certs);
+        }
+        if let Some(cert) = &options.client_cert {
+            builder = builder.identity(load_identity(options, cert)?);
+        }
+        if options.insecure == Some(true) {
+            warn!("已停用 TLS 憑證驗證（insecure）");
+            builder = builder
+                .tls_danger_accept_invalid_certs(true)
+                .tls_danger_accept_invalid_hostnames(true);
+        }
+
+        Ok(RedmineClient {
+            client: builder.build()?,
+            base_url: self.base_url,
+            switch_user: self.switch_user,
+            sys_api_key: self.sys_api_key,
+            text_format: self.text_format,
+            cache: self.cache,
+            retry: self.retry,
+        })
+    }
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>> {
+    std::fs::read(path)
+        .map_err(|e| RedmineError::Config(format!("無法讀取 {}: {e}", path.display())))
+}
+
+/// 依副檔名載入 PKCS#12 或 PEM 憑證 + 私鑰
+fn load_identity(options: &ConnectionOptions, cert: &Path) -> Result<Identity> {
+    let is_pkcs12 = cert
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("p12") || e.eq_ignore_ascii_case("pfx"));
+    let identity = if is_pkcs12 {
+        let password = options.client_cert_password.as_deref().unwrap_or("");
+        Identity::from_pkcs12_der(&read_file(cert)?, password)
+    } else {
+        let key = options.client_key.as_ref().ok_or_else(|| {
+            RedmineError::Config("PEM 用戶端憑證需要同時設定 client_key".into())
+        })?;
+        Identity::from_pkcs8_pem(&read_file(cert)?, &read_file(key)?)
+    };
+    identity.map_err(|e| RedmineError::Config(format!("無法載入用戶端憑證 {}: {e}", cert.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_prefers_override() {
+        let profile = ConnectionOptions {
+            proxy: Some("http://proxy:3128".into()),
+            insecure: Some(true),
+            ..Default::default()
+        };
+        let env = ConnectionOptions {
+            insecure: Some(false),
+            ..Default::default()
+        };
+        let merged = profile.clone().merge(env);
+        assert_eq!(merged.insecure, Some(false));
+        assert_eq!(merged.proxy.as_deref(), Some("http://proxy:3128"));
+
+        // 未設定的欄位沿用原值
+        assert_eq!(profile.clone().merge(ConnectionOptions::default()), profile);
+    }
+}
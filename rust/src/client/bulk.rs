@@ -0,0 +1,104 @@
+//! 批次更新 Issue — 以有限並行度逐一套用同一組更新參數
+
+use super::*;
+use futures::stream::{self, StreamExt};
+use serde::Deserialize;
+
+/// 預設並行度
+pub const DEFAULT_BULK_CONCURRENCY: usize = 4;
+/// 並行度上限，避免對伺服器造成壓力
+pub const MAX_BULK_CONCURRENCY: usize = 10;
+
+/// 單一 Issue 的批次更新結果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkUpdateResult {
+    pub id: u64,
+    pub subject: String,
+    pub status: String,
+    pub success: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 批次更新報告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkUpdateReport {
+    pub dry_run: bool,
+    pub total: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub changes: IssueUpdateParams,
+    pub results: Vec<BulkUpdateResult>,
+}
+
+impl IssueListParams {
+    /// 以 Issue ID 清單選取（不限狀態）
+    pub fn for_ids(ids: &[u64]) -> Self {
+        let ids: Vec<String> = ids.iter().map(u64::to_string).collect();
+        Self {
+            filters: IssueFilter::new()
+                .add("issue_id", FilterOp::Eq, [ids.join(",")])
+                .add("status_id", FilterOp::Any, Vec::<String>::new()),
+            ..Default::default()
+        }
+    }
+}
+
+impl RedmineClient {
+    /// 對多個 Issue 套用同一組更新
+    ///
+    /// `status` 為狀態名稱或 ID 時，會逐一依各 Issue 的工作流程驗證；
+    /// `dry_run` 時只做驗證（僅 GET），不送出任何修改。
+    pub async fn bulk_update_issues(
+        &self,
+        issues: &[Issue],
+        changes: &IssueUpdateParams,
+        status: Option<&str>,
+        dry_run: bool,
+        concurrency: usize,
+    ) -> BulkUpdateReport {
+        let concurrency = concurrency.clamp(1, MAX_BULK_CONCURRENCY);
+        let mut results: Vec<BulkUpdateResult> = stream::iter(issues)
+            .map(|issue| async move {
+                let outcome = self.bulk_update_one(issue.id, changes, status, dry_run).await;
+                BulkUpdateResult {
+                    id: issue.id,
+                    subject: issue.subject.clone(),
+                    status: issue.status.name.clone(),
+                    success: outcome.is_ok(),
+                    error: outcome.err().map(|e| e.to_string()),
+                }
+            })
+            .buffer_unordered(concurrency)
+            .collect()
+            .await;
+        results.sort_by_key(|r| r.id);
+
+        let succeeded = results.iter().filter(|r| r.success).count();
+        BulkUpdateReport {
+            dry_run,
+            total: results.len(),
+            succeeded,
+            failed: results.len() - succeeded,
+            changes: changes.clone(),
+            results,
+        }
+    }
+
+    async fn bulk_update_one(
+        &self,
+        id: u64,
+        changes: &IssueUpdateParams,
+        status: Option<&str>,
+        dry_run: bool,
+    ) -> Result<()> {
+        let mut params = changes.clone();
+        if let Some(status) = status {
+            params.status_id = Some(self.resolve_issue_status(id, status).await?);
+        }
+        if dry_run {
+            return Ok(());
+        }
+        self.update_issue(id, &params).await
+    }
+}
@@ -0,0 +1,139 @@
+//! 中繼資料快取 — trackers、狀態、優先權、活動、角色等幾乎不變的列表
+//!
+//! MCP 模式只存在記憶體；CLI 另外寫入 `~/.config/redmine/cache/<URL 雜湊>.json`，
+//! 讓多次執行共用。超過 TTL（`REDMINE_CACHE_TTL` 秒，預設 1 小時，0 停用）後重新抓取。
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::debug;
+
+/// 預設快取有效時間
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    /// 抓取時間（Unix 秒）
+    fetched_at: u64,
+    data: Value,
+}
+
+/// 以 API 路徑為 key 的回應快取，clone 後共用同一份資料
+#[derive(Clone)]
+pub struct MetadataCache {
+    entries: Arc<Mutex<HashMap<String, CacheEntry>>>,
+    ttl: Duration,
+    file: Option<PathBuf>,
+}
+
+impl MetadataCache {
+    /// 只存在記憶體（MCP 模式）
+    pub fn in_memory(ttl: Duration) -> Self {
+        Self {
+            entries: Arc::default(),
+            ttl,
+            file: None,
+        }
+    }
+
+    /// 存在記憶體並同步寫入此 URL 的快取檔（CLI 模式）
+    pub fn on_disk(url: &str, ttl: Duration) -> Self {
+        let file = Self::dir().map(|d| d.join(format!("{}.json", url_key(url))));
+        let entries = file
+            .as_ref()
+            .and_then(|f| std::fs::read_to_string(f).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self {
+            entries: Arc::new(Mutex::new(entries)),
+            ttl,
+            file,
+        }
+    }
+
+    /// 快取檔目錄
+    pub fn dir() -> Option<PathBuf> {
+        dirs::config_dir().map(|d| d.join("redmine").join("cache"))
+    }
+
+    /// 刪除所有 profile 的快取檔，回傳是否有東西被刪除
+    pub fn clear_all() -> std::io::Result<bool> {
+        match Self::dir() {
+            Some(dir) if dir.exists() => {
+                std::fs::remove_dir_all(dir)?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// 清除此快取（含快取檔）
+    pub fn clear(&self) {
+        self.entries.lock().clear();
+        if let Some(file) = &self.file {
+            let _ = std::fs::remove_file(file);
+        }
+    }
+
+    /// 取得未過期的快取
+    pub(super) fn get(&self, key: &str) -> Option<Value> {
+        if self.ttl.is_zero() {
+            return None;
+        }
+        let entries = self.entries.lock();
+        let entry = entries.get(key)?;
+        let age = now().saturating_sub(entry.fetched_at);
+        (age < self.ttl.as_secs()).then(|| entry.data.clone())
+    }
+
+    /// 寫入快取；快取檔寫入失敗只記錄不回報
+    pub(super) fn insert(&self, key: &str, data: Value) {
+        if self.ttl.is_zero() {
+            return;
+        }
+        let mut entries = self.entries.lock();
+        entries.insert(
+            key.to_string(),
+            CacheEntry {
+                fetched_at: now(),
+                data,
+            },
+        );
+        if let Some(file) = &self.file {
+            if let Err(e) = write_file(file, &entries) {
+                debug!("寫入快取檔 {} 失敗: {}", file.display(), e);
+            }
+        }
+    }
+}
+
+impl Default for MetadataCache {
+    fn default() -> Self {
+        Self::in_memory(DEFAULT_CACHE_TTL)
+    }
+}
+
+/// URL 的短雜湊，作為快取檔名
+fn url_key(url: &str) -> String {
+    let hash = format!("{:x}", Sha256::digest(url.trim_end_matches('/').as_bytes()));
+    hash[..16].to_string()
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn write_file(file: &Path, entries: &HashMap<String, CacheEntry>) -> std::io::Result<()> {
+    if let Some(parent) = file.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(file, serde_json::to_vec(entries)?)
+}
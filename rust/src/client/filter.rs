@@ -0,0 +1,561 @@
+//! Issue 進階篩選 — Redmine `f[]/op[]/v[]` 查詢語法
+//!
+//! 使用任何進階篩選時 Redmine 會忽略 `status_id`、`tracker_id`、`assigned_to_id`
+//! 這類簡易參數，因此 [`IssueFilter::query_pairs`] 會把它們一併轉成篩選條件；
+//! 未指定狀態時補上 `status_id=o`，與不帶篩選時的預設行為一致。
+
+use super::IssueListParams;
+use crate::error::{RedmineError, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// 篩選運算子
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum FilterOp {
+    /// `=` 等於（多值為 OR）
+    Eq,
+    /// `!` 不等於
+    NotEq,
+    /// `o` 未結案（僅 status_id）
+    Open,
+    /// `c` 已結案（僅 status_id）
+    Closed,
+    /// `*` 有值 / 任意
+    Any,
+    /// `!*` 無值
+    None,
+    /// `>=`
+    GreaterEq,
+    /// `<=`
+    LessEq,
+    /// `><` 介於兩值之間
+    Between,
+    /// `>t-` N 天內
+    LessThanDaysAgo,
+    /// `<t-` 超過 N 天前
+    MoreThanDaysAgo,
+    /// `><t-` 過去 N 天內的區間（兩值）
+    BetweenDaysAgo,
+    /// `t-` 剛好 N 天前
+    DaysAgo,
+    /// `<t+` N 天內到期
+    InLessThanDays,
+    /// `>t+` N 天後
+    InMoreThanDays,
+    /// `t+` 剛好 N 天後
+    InDays,
+    /// `t` 今天
+    Today,
+    /// `ld` 昨天
+    Yesterday,
+    /// `w` 本週
+    ThisWeek,
+    /// `lw` 上週
+    LastWeek,
+    /// `l2w` 最近兩週
+    LastTwoWeeks,
+    /// `m` 本月
+    ThisMonth,
+    /// `lm` 上個月
+    LastMonth,
+    /// `y` 今年
+    ThisYear,
+    /// `~` 包含文字
+    Contains,
+    /// `!~` 不包含文字
+    NotContains,
+    /// `^` 開頭為
+    StartsWith,
+    /// `$` 結尾為
+    EndsWith,
+}
+
+/// 運算子需要的值個數
+enum Arity {
+    Zero,
+    Two,
+    AtLeastOne,
+}
+
+impl FilterOp {
+    const ALL: [FilterOp; 28] = [
+        Self::Eq,
+        Self::NotEq,
+        Self::Open,
+        Self::Closed,
+        Self::Any,
+        Self::None,
+        Self::GreaterEq,
+        Self::LessEq,
+        Self::Between,
+        Self::LessThanDaysAgo,
+        Self::MoreThanDaysAgo,
+        Self::BetweenDaysAgo,
+        Self::DaysAgo,
+        Self::InLessThanDays,
+        Self::InMoreThanDays,
+        Self::InDays,
+        Self::Today,
+        Self::Yesterday,
+        Self::ThisWeek,
+        Self::LastWeek,
+        Self::LastTwoWeeks,
+        Self::ThisMonth,
+        Self::LastMonth,
+        Self::ThisYear,
+        Self::Contains,
+        Self::NotContains,
+        Self::StartsWith,
+        Self::EndsWith,
+    ];
+
+    /// Redmine 運算子代碼
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Eq => "=",
+            Self::NotEq => "!",
+            Self::Open => "o",
+            Self::Closed => "c",
+            Self::Any => "*",
+            Self::None => "!*",
+            Self::GreaterEq => ">=",
+            Self::LessEq => "<=",
+            Self::Between => "><",
+            Self::LessThanDaysAgo => ">t-",
+            Self::MoreThanDaysAgo => "<t-",
+            Self::BetweenDaysAgo => "><t-",
+            Self::DaysAgo => "t-",
+            Self::InLessThanDays => "<t+",
+            Self::InMoreThanDays => ">t+",
+            Self::InDays => "t+",
+            Self::Today => "t",
+            Self::Yesterday => "ld",
+            Self::ThisWeek => "w",
+            Self::LastWeek => "lw",
+            Self::LastTwoWeeks => "l2w",
+            Self::ThisMonth => "m",
+            Self::LastMonth => "lm",
+            Self::ThisYear => "y",
+            Self::Contains => "~",
+            Self::NotContains => "!~",
+            Self::StartsWith => "^",
+            Self::EndsWith => "$",
+        }
+    }
+
+    fn arity(self) -> Arity {
+        match self {
+            Self::Open
+            | Self::Closed
+            | Self::Any
+            | Self::None
+            | Self::Today
+            | Self::Yesterday
+            | Self::ThisWeek
+            | Self::LastWeek
+            | Self::LastTwoWeeks
+            | Self::ThisMonth
+            | Self::LastMonth
+            | Self::ThisYear => Arity::Zero,
+            Self::Between | Self::BetweenDaysAgo => Arity::Two,
+            _ => Arity::AtLeastOne,
+        }
+    }
+}
+
+impl fmt::Display for FilterOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for FilterOp {
+    type Err = RedmineError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|op| op.as_str() == s)
+            .ok_or_else(|| RedmineError::InvalidFilter(format!("未知的運算子 '{s}'")))
+    }
+}
+
+impl TryFrom<String> for FilterOp {
+    type Error = RedmineError;
+
+    fn try_from(s: String) -> Result<Self> {
+        s.parse()
+    }
+}
+
+impl From<FilterOp> for String {
+    fn from(op: FilterOp) -> Self {
+        op.as_str().to_string()
+    }
+}
+
+/// 單一篩選條件
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilterCondition {
+    pub field: String,
+    pub op: FilterOp,
+    #[serde(default)]
+    pub values: Vec<String>,
+}
+
+impl FilterCondition {
+    pub fn new<I, S>(field: &str, op: FilterOp, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            field: field.to_string(),
+            op,
+            values: values.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// 檢查欄位名稱與值個數
+    pub fn validate(&self) -> Result<()> {
+        let valid_field = !self.field.is_empty()
+            && self
+                .field
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+        if !valid_field {
+            return Err(RedmineError::InvalidFilter(format!("無效的欄位 '{}'", self.field)));
+        }
+        let ok = match self.op.arity() {
+            Arity::Zero => self.values.is_empty(),
+            Arity::Two => self.values.len() == 2,
+            Arity::AtLeastOne => !self.values.is_empty(),
+        };
+        if !ok {
+            let expected = match self.op.arity() {
+                Arity::Zero => "不需要值",
+                Arity::Two => "需要兩個值",
+                Arity::AtLeastOne => "至少需要一個值",
+            };
+            return Err(RedmineError::InvalidFilter(format!(
+                "{} {} {expected}，收到 {} 個",
+                self.field,
+                self.op,
+                self.values.len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// 解析簡易參數的值（例如 `open`、`!42`、`1|2`），規則同 Redmine 的 short filter
+    fn from_short(field: &str, value: &str) -> Self {
+        match value {
+            "o" | "open" => Self::new(field, FilterOp::Open, Vec::<String>::new()),
+            "c" | "closed" => Self::new(field, FilterOp::Closed, Vec::<String>::new()),
+            "*" => Self::new(field, FilterOp::Any, Vec::<String>::new()),
+            "!*" => Self::new(field, FilterOp::None, Vec::<String>::new()),
+            v => match v.strip_prefix('!') {
+                Some(rest) => Self::new(field, FilterOp::NotEq, rest.split('|')),
+                None => Self::new(field, FilterOp::Eq, v.split('|')),
+            },
+        }
+    }
+}
+
+/// 解析 CLI 的 `field:op[:value]`，多個值以 `|` 分隔
+impl FromStr for FilterCondition {
+    type Err = RedmineError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (field, rest) = s.split_once(':').ok_or_else(|| {
+            RedmineError::InvalidFilter(format!("'{s}' 格式應為 field:op[:value]"))
+        })?;
+        let (op, value) = rest.split_once(':').unwrap_or((rest, ""));
+        let values: Vec<&str> = if value.is_empty() {
+            Vec::new()
+        } else {
+            value.split('|').collect()
+        };
+        let condition = Self::new(field, op.parse()?, values);
+        condition.validate()?;
+        Ok(condition)
+    }
+}
+
+/// Issue 篩選條件建構器
+///
+/// ```ignore
+/// let filter = IssueFilter::new()
+///     .updated_on(FilterOp::LessThanDaysAgo, ["7"])
+///     .custom_field(12, FilterOp::Eq, ["iOS"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct IssueFilter {
+    conditions: Vec<FilterCondition>,
+}
+
+impl IssueFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.conditions.is_empty()
+    }
+
+    pub fn conditions(&self) -> &[FilterCondition] {
+        &self.conditions
+    }
+
+    /// 加入任意欄位的條件
+    pub fn add<I, S>(mut self, field: &str, op: FilterOp, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.push(FilterCondition::new(field, op, values));
+        self
+    }
+
+    /// 加入條件；Redmine 每個欄位只接受一個條件，重複的欄位由 [`validate`](Self::validate) 回報
+    pub fn push(&mut self, condition: FilterCondition) {
+        self.conditions.push(condition);
+    }
+
+    pub fn created_on<I: IntoIterator<Item = S>, S: Into<String>>(self, op: FilterOp, values: I) -> Self {
+        self.add("created_on", op, values)
+    }
+
+    pub fn updated_on<I: IntoIterator<Item = S>, S: Into<String>>(self, op: FilterOp, values: I) -> Self {
+        self.add("updated_on", op, values)
+    }
+
+    pub fn due_date<I: IntoIterator<Item = S>, S: Into<String>>(self, op: FilterOp, values: I) -> Self {
+        self.add("due_date", op, values)
+    }
+
+    pub fn author<I: IntoIterator<Item = S>, S: Into<String>>(self, op: FilterOp, values: I) -> Self {
+        self.add("author_id", op, values)
+    }
+
+    pub fn category<I: IntoIterator<Item = S>, S: Into<String>>(self, op: FilterOp, values: I) -> Self {
+        self.add("category_id", op, values)
+    }
+
+    pub fn fixed_version<I: IntoIterator<Item = S>, S: Into<String>>(self, op: FilterOp, values: I) -> Self {
+        self.add("fixed_version_id", op, values)
+    }
+
+    pub fn parent<I: IntoIterator<Item = S>, S: Into<String>>(self, op: FilterOp, values: I) -> Self {
+        self.add("parent_id", op, values)
+    }
+
+    pub fn subproject<I: IntoIterator<Item = S>, S: Into<String>>(self, op: FilterOp, values: I) -> Self {
+        self.add("subproject_id", op, values)
+    }
+
+    /// 自訂欄位 `cf_N`
+    pub fn custom_field<I: IntoIterator<Item = S>, S: Into<String>>(
+        self,
+        id: u64,
+        op: FilterOp,
+        values: I,
+    ) -> Self {
+        self.add(&format!("cf_{id}"), op, values)
+    }
+
+    /// 主旨包含文字（`~`）
+    pub fn subject_contains(self, text: &str) -> Self {
+        self.add("subject", FilterOp::Contains, [text])
+    }
+
+    /// 檢查每個條件，並拒絕同一欄位的多個條件（日期區間請用 `><`）
+    pub fn validate(&self) -> Result<()> {
+        self.conditions.iter().try_for_each(FilterCondition::validate)?;
+        for (i, c) in self.conditions.iter().enumerate() {
+            if self.conditions[..i].iter().any(|prev| prev.field == c.field) {
+                return Err(RedmineError::InvalidFilter(format!(
+                    "欄位 {} 有多個條件，Redmine 每個欄位只接受一個（範圍請用 {}:><:FROM|TO）",
+                    c.field, c.field
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// 組出 `/issues.json` 的查詢參數，包含列表參數與篩選條件
+    ///
+    /// 指定 `query_id` 時 Redmine 只套用已儲存查詢的條件，因此不接受其他篩選。
+    pub fn query_pairs(&self, params: &IssueListParams) -> Result<Vec<(String, String)>> {
+        self.validate()?;
+        if params.query_id.is_some() {
+            let has_filters = !self.conditions.is_empty()
+                || params.status_id.is_some()
+                || params.assigned_to_id.is_some()
+                || params.tracker_id.is_some();
+            if has_filters {
+                return Err(RedmineError::InvalidFilter(
+                    "已儲存查詢（query_id）不能與其他篩選條件同時使用，Redmine 會忽略這些條件".into(),
+                ));
+            }
+        }
+
+        let mut filter = self.clone();
+        for (field, value) in [
+            ("status_id", &params.status_id),
+            ("assigned_to_id", &params.assigned_to_id),
+        ] {
+            if let Some(value) = value {
+                if !filter.has(field) {
+                    filter.push(FilterCondition::from_short(field, value));
+                }
+            }
+        }
+        if let Some(tracker_id) = params.tracker_id {
+            if !filter.has("tracker_id") {
+                filter.push(FilterCondition::new("tracker_id", FilterOp::Eq, [tracker_id.to_string()]));
+            }
+        }
+        if !filter.has("status_id") && params.query_id.is_none() {
+            filter.push(FilterCondition::new("status_id", FilterOp::Open, Vec::<String>::new()));
+        }
+
+        let mut pairs = Vec::new();
+        if params.query_id.is_none() {
+            pairs.push(("set_filter".to_string(), "1".to_string()));
+        }
+        let passthrough = [
+            ("project_id", params.project_id.clone()),
+            ("query_id", params.query_id.map(|v| v.to_string())),
+            ("limit", params.limit.map(|v| v.to_string())),
+            ("offset", params.offset.map(|v| v.to_string())),
+            ("sort", params.sort.clone()),
+        ];
+        pairs.extend(
+            passthrough
+                .into_iter()
+                .filter_map(|(k, v)| v.map(|v| (k.to_string(), v))),
+        );
+        for c in &filter.conditions {
+            pairs.push(("f[]".to_string(), c.field.clone()));
+            pairs.push((format!("op[{}]", c.field), c.op.to_string()));
+            for v in &c.values {
+                pairs.push((format!("v[{}][]", c.field), v.clone()));
+            }
+        }
+        Ok(pairs)
+    }
+
+    fn has(&self, field: &str) -> bool {
+        self.conditions.iter().any(|c| c.field == field)
+    }
+}
+
+impl From<Vec<FilterCondition>> for IssueFilter {
+    fn from(conditions: Vec<FilterCondition>) -> Self {
+        Self { conditions }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(filter: IssueFilter, params: IssueListParams) -> Result<Vec<(String, String)>> {
+        filter.query_pairs(&params)
+    }
+
+    fn has(pairs: &[(String, String)], key: &str, value: &str) -> bool {
+        pairs.iter().any(|(k, v)| k == key && v == value)
+    }
+
+    #[test]
+    fn parse_condition() {
+        let c: FilterCondition = "updated_on:>t-:7".parse().unwrap();
+        assert_eq!(c, FilterCondition::new("updated_on", FilterOp::LessThanDaysAgo, ["7"]));
+
+        let c: FilterCondition = "created_on:><:2024-01-01|2024-01-31".parse().unwrap();
+        assert_eq!(c.values, ["2024-01-01", "2024-01-31"]);
+
+        let c: FilterCondition = "assigned_to_id:!*".parse().unwrap();
+        assert_eq!(c.op, FilterOp::None);
+        assert!(c.values.is_empty());
+
+        // 值中的 `:` 保留（例如時間）
+        let c: FilterCondition = "subject:~:10:30".parse().unwrap();
+        assert_eq!(c.values, ["10:30"]);
+    }
+
+    #[test]
+    fn parse_condition_errors() {
+        for input in [
+            "subject",               // 缺少運算子
+            "subject:?:x",           // 未知運算子
+            "subject:~",             // 缺少值
+            "status_id:o:1",         // 多餘的值
+            "created_on:><:2024-01", // 區間需要兩個值
+            "bad field:=:1",         // 無效欄位
+        ] {
+            assert!(
+                matches!(input.parse::<FilterCondition>(), Err(RedmineError::InvalidFilter(_))),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn defaults_to_open_status() {
+        let pairs = pairs(IssueFilter::new().subject_contains("crash"), IssueListParams::default()).unwrap();
+        assert!(has(&pairs, "set_filter", "1"));
+        assert!(has(&pairs, "f[]", "status_id"));
+        assert!(has(&pairs, "op[status_id]", "o"));
+        assert!(has(&pairs, "op[subject]", "~"));
+        assert!(has(&pairs, "v[subject][]", "crash"));
+    }
+
+    #[test]
+    fn short_params_become_conditions() {
+        let params = IssueListParams {
+            status_id: Some("closed".into()),
+            assigned_to_id: Some("!5|6".into()),
+            tracker_id: Some(2),
+            ..Default::default()
+        };
+        let pairs = pairs(IssueFilter::new(), params).unwrap();
+        assert!(has(&pairs, "op[status_id]", "c"));
+        assert!(has(&pairs, "op[assigned_to_id]", "!"));
+        assert!(has(&pairs, "v[assigned_to_id][]", "5"));
+        assert!(has(&pairs, "v[assigned_to_id][]", "6"));
+        assert!(has(&pairs, "v[tracker_id][]", "2"));
+        assert!(!has(&pairs, "op[status_id]", "o"));
+    }
+
+    #[test]
+    fn duplicate_fields_are_rejected() {
+        let filter = IssueFilter::from(vec![
+            FilterCondition::new("created_on", FilterOp::GreaterEq, ["2024-01-01"]),
+            FilterCondition::new("created_on", FilterOp::LessEq, ["2024-01-31"]),
+        ]);
+        assert!(matches!(
+            pairs(filter, IssueListParams::default()),
+            Err(RedmineError::InvalidFilter(_))
+        ));
+    }
+
+    #[test]
+    fn saved_query_rejects_filters() {
+        let query = || IssueListParams { query_id: Some(7), ..Default::default() };
+
+        let pairs_ok = pairs(IssueFilter::new(), query()).unwrap();
+        assert!(has(&pairs_ok, "query_id", "7"));
+        assert!(!pairs_ok.iter().any(|(k, _)| k == "set_filter" || k == "f[]"));
+
+        assert!(pairs(IssueFilter::new().subject_contains("x"), query()).is_err());
+        let with_status = IssueListParams { status_id: Some("o".into()), ..query() };
+        assert!(pairs(IssueFilter::new(), with_status).is_err());
+    }
+}
@@ -1,14 +1,34 @@
 //! Redmine API 客戶端
 
+mod builder;
+mod auth;
+mod bulk;
+mod cache;
+mod filter;
+mod paginate;
+mod permissions;
+mod resolve;
+mod retry;
 mod types;
 
+pub use auth::*;
+pub use builder::*;
+pub use bulk::*;
+pub use cache::*;
+pub use filter::*;
+pub use paginate::*;
+pub use permissions::*;
+pub use retry::RetryPolicy;
 pub use types::*;
 
 use crate::error::{RedmineError, Result};
+use crate::markup::TextFormat;
 use reqwest::{header, Client, Response};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
+use std::collections::{hash_map::Entry, BTreeSet, HashMap};
 use std::path::Path;
+use std::time::Duration;
 use tracing::{debug, info};
 
 /// Redmine API 客戶端
@@ -16,37 +36,56 @@ use tracing::{debug, info};
 pub struct RedmineClient {
     client: Client,
     base_url: String,
-    api_key: String,
+    /// 代理的使用者登入帳號（`X-Redmine-Switch-User`）
+    switch_user: Option<String>,
+    /// 版本庫管理 Web Service 的 API 金鑰（`/sys` 端點）
+    sys_api_key: Option<String>,
+    text_format: TextFormat,
+    cache: MetadataCache,
+    retry: RetryPolicy,
 }
 
+/// 預設請求逾時
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
 impl RedmineClient {
-    /// 建立新的客戶端
+    /// 建立新的客戶端（預設設定）
     pub fn new(base_url: &str, api_key: &str) -> Result<Self> {
-        let mut headers = header::HeaderMap::new();
-        headers.insert(
-            "X-Redmine-API-Key",
-            header::HeaderValue::from_str(api_key)
-                .map_err(|_| RedmineError::Config("無效的 API Key".into()))?,
-        );
-        headers.insert(
-            header::CONTENT_TYPE,
-            header::HeaderValue::from_static("application/json"),
-        );
-        headers.insert(
-            header::USER_AGENT,
-            header::HeaderValue::from_static("MitakeRedmineMCP/1.0"),
-        );
+        Self::builder(base_url, api_key).build()
+    }
 
-        let client = Client::builder()
-            .default_headers(headers)
-            .timeout(std::time::Duration::from_secs(30))
-            .build()?;
+    /// 建立可設定逾時、代理與 TLS 的建構器
+    pub fn builder(base_url: &str, api_key: &str) -> RedmineClientBuilder {
+        RedmineClientBuilder::new(base_url, api_key)
+    }
 
-        Ok(Self {
-            client,
-            base_url: base_url.trim_end_matches('/').to_string(),
-            api_key: api_key.to_string(),
-        })
+    /// 設定伺服器文字格式（Redmine API 無法查詢，需由設定指定）
+    pub fn with_text_format(mut self, text_format: TextFormat) -> Self {
+        self.text_format = text_format;
+        self
+    }
+
+    /// 伺服器文字格式
+    pub fn text_format(&self) -> TextFormat {
+        self.text_format
+    }
+
+    /// 中繼資料快取
+    pub fn cache(&self) -> &MetadataCache {
+        &self.cache
+    }
+
+    /// 以指定登入帳號的身分呼叫的複本（`X-Redmine-Switch-User`，需管理員認證）
+    pub fn as_user(&self, login: &str) -> Self {
+        Self {
+            switch_user: Some(login.to_string()),
+            ..self.clone()
+        }
+    }
+
+    /// 目前代理的使用者
+    pub fn switch_user(&self) -> Option<&str> {
+        self.switch_user.as_deref()
     }
 
     /// 測試連線
@@ -67,13 +106,46 @@ impl RedmineClient {
 
     /// 取得 Issue 列表
     pub async fn get_issues(&self, params: &IssueListParams) -> Result<IssueListResponse> {
-        self.get_with_query("/issues.json", params).await
+        self.get_with_query("/issues.json", &issue_query_pairs(params)?)
+            .await
     }
 
     /// 取得單一 Issue
     pub async fn get_issue(&self, id: u64) -> Result<IssueResponse> {
-        self.get(&format!("/issues/{}.json?include=journals,attachments", id))
-            .await
+        self.get(&format!(
+            "/issues/{}.json?include=journals,attachments,allowed_statuses",
+            id
+        ))
+        .await
+    }
+
+    /// 將狀態名稱（不分大小寫）或 ID 解析為狀態 ID，並依工作流程驗證轉換
+    ///
+    /// 伺服器不支援 `allowed_statuses`（Redmine 5.1 以前）時只檢查狀態是否存在。
+    pub async fn resolve_issue_status(&self, issue_id: u64, status: &str) -> Result<u64> {
+        let resp: IssueResponse = self
+            .get(&format!("/issues/{}.json?include=allowed_statuses", issue_id))
+            .await?;
+        let candidates: Vec<IdName> = match resp.issue.allowed_statuses {
+            Some(allowed) => allowed,
+            None => self
+                .get_statuses()
+                .await?
+                .issue_statuses
+                .into_iter()
+                .map(|s| IdName { id: s.id, name: s.name })
+                .collect(),
+        };
+        let wanted_id = status.parse::<u64>().ok();
+        candidates
+            .iter()
+            .find(|s| Some(s.id) == wanted_id || s.name.eq_ignore_ascii_case(status))
+            .map(|s| s.id)
+            .ok_or_else(|| RedmineError::InvalidStatusTransition {
+                issue_id,
+                status: status.to_string(),
+                allowed: candidates.iter().map(|s| s.name.clone()).collect(),
+            })
     }
 
     /// 更新 Issue
@@ -88,20 +160,96 @@ impl RedmineClient {
             .await
     }
 
+    /// 新增 Issue 備註（不變更其他欄位）
+    pub async fn add_issue_note(&self, issue_id: u64, notes: &str, private: bool) -> Result<()> {
+        let params = IssueUpdateParams {
+            notes: Some(notes.to_string()),
+            private_notes: private.then_some(true),
+            ..Default::default()
+        };
+        self.update_issue(issue_id, &params).await
+    }
+
+    /// 修改 Journal 備註或私人標記（Redmine 5+）
+    pub async fn update_journal(&self, journal_id: u64, params: &JournalParams) -> Result<()> {
+        let body = serde_json::json!({ "journal": params });
+        self.put(&format!("/journals/{}.json", journal_id), &body).await
+    }
+
+    /// 取得與 Issue 關聯的版本庫提交（經由 `include=changesets`）
+    pub async fn get_issue_changesets(&self, issue_id: u64) -> Result<Vec<Changeset>> {
+        let resp: IssueResponse = self
+            .get(&format!("/issues/{}.json?include=changesets", issue_id))
+            .await?;
+        Ok(resp.issue.changesets.unwrap_or_default())
+    }
+
     // ========== Projects ==========
 
     /// 取得專案列表
-    pub async fn get_projects(&self) -> Result<ProjectListResponse> {
-        self.get("/projects.json?limit=100").await
+    ///
+    /// 自動分頁，最多 `max_items` 筆（預設 [`MAX_ITEMS_LIMIT`]）。
+    pub async fn get_projects(&self, max_items: Option<u64>) -> Result<ProjectListResponse> {
+        let max_items = max_items.unwrap_or(MAX_ITEMS_LIMIT);
+        let (projects, total_count) = self
+            .fetch_all::<ProjectListResponse>("/projects.json", Vec::new(), Some(max_items))
+            .await?;
+        Ok(ProjectListResponse { projects, total_count })
     }
 
-    /// 取得專案成員
-    pub async fn get_project_members(&self, project_id: &str) -> Result<MembershipListResponse> {
-        self.get(&format!(
-            "/projects/{}/memberships.json?limit=100",
-            project_id
-        ))
-        .await
+    /// 取得單一專案（含啟用模組）
+    pub async fn get_project(&self, project_id: &str) -> Result<ProjectResponse> {
+        self.get(&format!("/projects/{}.json?include=enabled_modules", project_id))
+            .await
+    }
+
+    /// 取得專案成員（自動分頁，最多 `max_items` 筆，預設 [`MAX_ITEMS_LIMIT`]）
+    pub async fn get_project_members(
+        &self,
+        project_id: &str,
+        max_items: Option<u64>,
+    ) -> Result<MembershipListResponse> {
+        let path = format!("/projects/{}/memberships.json", project_id);
+        let max_items = max_items.unwrap_or(MAX_ITEMS_LIMIT);
+        let (memberships, total_count) = self
+            .fetch_all::<MembershipListResponse>(&path, Vec::new(), Some(max_items))
+            .await?;
+        Ok(MembershipListResponse { memberships, total_count })
+    }
+
+    /// 取得專案的版本庫
+    ///
+    /// Redmine REST API 沒有版本庫端點，改用版本庫管理 Web Service（`/sys/projects.json`）：
+    /// 需在「管理 → 設定 → 版本庫」啟用並設定 API 金鑰（[`RedmineClientBuilder::sys_api_key`]）。
+    /// 此端點只回傳專案的主版本庫；未啟用版本庫模組的專案回傳空列表。
+    pub async fn get_project_repositories(&self, project_id: &str) -> Result<Vec<Repository>> {
+        let key = self.sys_api_key.as_deref().ok_or_else(|| {
+            RedmineError::Config("列出版本庫需要版本庫管理 Web Service 的 API 金鑰（REDMINE_SYS_API_KEY）".into())
+        })?;
+        let project = self.get_project(project_id).await?.project;
+
+        let url = format!("{}/sys/projects.json", self.base_url);
+        debug!("GET {}", url);
+        // 直接送出：金鑰在查詢字串中，不經過代理使用者標頭與稽核紀錄
+        let response = self
+            .client
+            .get(format!("{url}?key={}", urlencoding::encode(key)))
+            .send()
+            .await?;
+        let entries: Vec<SysProjectEntry> = match Self::handle_response(response).await {
+            Err(RedmineError::Forbidden) => {
+                return Err(RedmineError::Config(
+                    "版本庫管理 Web Service 未啟用或 REDMINE_SYS_API_KEY 不正確".into(),
+                ));
+            }
+            result => result?,
+        };
+        Ok(entries
+            .into_iter()
+            .map(SysProjectEntry::into_inner)
+            .filter(|p| p.id == project.id)
+            .filter_map(|p| p.repository)
+            .collect())
     }
 
     // ========== Users ==========
@@ -122,21 +270,51 @@ impl RedmineClient {
             .await
     }
 
+    /// 建立使用者（需管理員權限）
+    pub async fn create_user(&self, params: &UserCreateParams) -> Result<UserResponse> {
+        let body = serde_json::json!({ "user": params });
+        self.post("/users.json", &body).await
+    }
+
+    /// 更新使用者（需管理員權限；鎖定/解鎖以 `status` 設定）
+    pub async fn update_user(&self, id: u64, params: &UserUpdateParams) -> Result<()> {
+        let body = serde_json::json!({ "user": params });
+        self.put(&format!("/users/{}.json", id), &body).await
+    }
+
+    /// 刪除使用者（需管理員權限）
+    pub async fn delete_user(&self, id: u64) -> Result<()> {
+        self.delete(&format!("/users/{}.json", id)).await
+    }
+
+    /// 將使用者加入群組（需管理員權限）
+    pub async fn add_user_to_group(&self, group_id: u64, user_id: u64) -> Result<()> {
+        let body = serde_json::json!({ "user_id": user_id });
+        self.post_no_content(&format!("/groups/{}/users.json", group_id), &body)
+            .await
+    }
+
+    /// 將使用者移出群組（需管理員權限）
+    pub async fn remove_user_from_group(&self, group_id: u64, user_id: u64) -> Result<()> {
+        self.delete(&format!("/groups/{}/users/{}.json", group_id, user_id))
+            .await
+    }
+
     // ========== Metadata ==========
 
     /// 取得 Trackers
     pub async fn get_trackers(&self) -> Result<TrackerListResponse> {
-        self.get("/trackers.json").await
+        self.get_cached("/trackers.json").await
     }
 
     /// 取得狀態列表
     pub async fn get_statuses(&self) -> Result<StatusListResponse> {
-        self.get("/issue_statuses.json").await
+        self.get_cached("/issue_statuses.json").await
     }
 
     /// 取得優先權列表
     pub async fn get_priorities(&self) -> Result<PriorityListResponse> {
-        self.get("/enumerations/issue_priorities.json").await
+        self.get_cached("/enumerations/issue_priorities.json").await
     }
 
     // ========== Time Entries ==========
@@ -157,7 +335,7 @@ impl RedmineClient {
 
     /// 取得工時活動類型
     pub async fn get_time_entry_activities(&self) -> Result<TimeEntryActivityListResponse> {
-        self.get("/enumerations/time_entry_activities.json").await
+        self.get_cached("/enumerations/time_entry_activities.json").await
     }
 
     // ========== Versions ==========
@@ -227,7 +405,110 @@ impl RedmineClient {
         .await
     }
 
-    /// 更新 Wiki 頁面
+    /// 取得 Wiki 頁面指定版本
+    pub async fn get_wiki_page_version(
+        &self,
+        project_id: &str,
+        title: &str,
+        version: u64,
+    ) -> Result<WikiPageResponse> {
+        let encoded_title = urlencoding::encode(title);
+        self.get(&format!(
+            "/projects/{}/wiki/{}/{}.json",
+            project_id, encoded_title, version
+        ))
+        .await
+    }
+
+    /// 取得 Wiki 頁面歷史
+    ///
+    /// Redmine REST API 沒有歷史列表端點，因此從最新版本往回逐版讀取。
+    /// `limit` 限制回傳的版本數（預設全部），已被刪除的版本會略過。
+    pub async fn get_wiki_page_history(
+        &self,
+        project_id: &str,
+        title: &str,
+        limit: Option<u64>,
+    ) -> Result<WikiPageHistoryResponse> {
+        let latest = self.get_wiki_page(project_id, title).await?.wiki_page;
+        let oldest = match limit {
+            Some(n) if n > 0 => latest.version.saturating_sub(n - 1).max(1),
+            _ => 1,
+        };
+
+        let mut versions = vec![WikiPageVersion {
+            version: latest.version,
+            author: latest.author,
+            comments: latest.comments,
+            updated_on: latest.updated_on,
+        }];
+        for v in (oldest..latest.version).rev() {
+            match self.get_wiki_page_version(project_id, title, v).await {
+                Ok(resp) => {
+                    let p = resp.wiki_page;
+                    versions.push(WikiPageVersion {
+                        version: p.version,
+                        author: p.author,
+                        comments: p.comments,
+                        updated_on: p.updated_on,
+                    });
+                }
+                Err(RedmineError::NotFound { .. }) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(WikiPageHistoryResponse {
+            title: latest.title,
+            versions,
+        })
+    }
+
+    /// 比較 Wiki 頁面兩個版本（本地產生 unified diff）
+    ///
+    /// `to` 預設為最新版本，`from` 預設為 `to` 的前一版
+    pub async fn diff_wiki_page(
+        &self,
+        project_id: &str,
+        title: &str,
+        from: Option<u64>,
+        to: Option<u64>,
+    ) -> Result<WikiPageDiff> {
+        let new = match to {
+            Some(v) => self.get_wiki_page_version(project_id, title, v).await?,
+            None => self.get_wiki_page(project_id, title).await?,
+        }
+        .wiki_page;
+        let from = from.unwrap_or_else(|| new.version.saturating_sub(1));
+        let old_text = if from == 0 {
+            String::new()
+        } else {
+            self.get_wiki_page_version(project_id, title, from)
+                .await?
+                .wiki_page
+                .text
+        };
+
+        let diff = similar::TextDiff::from_lines(&old_text, &new.text)
+            .unified_diff()
+            .context_radius(3)
+            .header(
+                &format!("{}@v{}", new.title, from),
+                &format!("{}@v{}", new.title, new.version),
+            )
+            .to_string();
+
+        Ok(WikiPageDiff {
+            title: new.title,
+            from,
+            to: new.version,
+            diff,
+        })
+    }
+
+    /// 更新 Wiki 頁面（頁面不存在時會建立）
+    ///
+    /// 若 `params.version` 與伺服器目前版本不符，回傳 `RedmineError::Conflict`
     pub async fn update_wiki_page(
         &self,
         project_id: &str,
@@ -243,6 +524,99 @@ impl RedmineClient {
         .await
     }
 
+    /// 刪除 Wiki 頁面（子頁面保留並移至頂層）
+    pub async fn delete_wiki_page(&self, project_id: &str, title: &str) -> Result<()> {
+        let encoded_title = urlencoding::encode(title);
+        self.delete(&format!(
+            "/projects/{}/wiki/{}.json?todo=nullify",
+            project_id, encoded_title
+        ))
+        .await
+    }
+
+    /// 重新命名 Wiki 頁面
+    ///
+    /// Redmine REST API 不提供改名端點，因此以「建立新頁面 → 子頁面改掛新頁面」實作。
+    /// 舊頁面預設保留並改為指向新頁面的連結，版本歷史與附件不會遺失；
+    /// `delete_old` 時才刪除舊頁面，且只在所有子頁面都改掛成功後執行。
+    pub async fn rename_wiki_page(
+        &self,
+        project_id: &str,
+        title: &str,
+        new_title: &str,
+        delete_old: bool,
+    ) -> Result<WikiRenameResult> {
+        let old = self.get_wiki_page(project_id, title).await?.wiki_page;
+
+        match self.get_wiki_page(project_id, new_title).await {
+            Ok(_) => {
+                return Err(RedmineError::Conflict {
+                    message: format!("Wiki 頁面 '{new_title}' 已存在"),
+                });
+            }
+            Err(RedmineError::NotFound { .. }) => {}
+            Err(e) => return Err(e),
+        }
+
+        let params = WikiPageParams {
+            text: old.text.clone(),
+            comments: Some(format!("Renamed from {}", old.title)),
+            version: None,
+            parent_title: old.parent.as_ref().map(|p| p.title.clone()),
+        };
+        self.update_wiki_page(project_id, new_title, &params).await?;
+
+        // 子頁面改掛到新頁面；失敗的記錄下來，不中斷其他子頁面
+        let mut moved_children = Vec::new();
+        let mut failed_children = Vec::new();
+        let pages = self.get_wiki_pages(project_id).await?;
+        for child in pages
+            .wiki_pages
+            .iter()
+            .filter(|p| p.parent.as_ref().is_some_and(|parent| parent.title == old.title))
+        {
+            match self.reparent_wiki_page(project_id, &child.title, new_title).await {
+                Ok(()) => moved_children.push(child.title.clone()),
+                Err(e) => failed_children.push(WikiRenameFailure {
+                    title: child.title.clone(),
+                    error: e.to_string(),
+                }),
+            }
+        }
+
+        let old_page_deleted = delete_old && failed_children.is_empty();
+        if old_page_deleted {
+            self.delete_wiki_page(project_id, &old.title).await?;
+        } else {
+            let params = WikiPageParams {
+                text: format!("This page has been renamed to [[{new_title}]]."),
+                comments: Some(format!("Renamed to {new_title}")),
+                version: Some(old.version),
+                parent_title: old.parent.as_ref().map(|p| p.title.clone()),
+            };
+            self.update_wiki_page(project_id, &old.title, &params).await?;
+        }
+
+        Ok(WikiRenameResult {
+            wiki_page: self.get_wiki_page(project_id, new_title).await?.wiki_page,
+            moved_children,
+            failed_children,
+            old_page_deleted,
+        })
+    }
+
+    /// 將頁面改掛到另一個父頁面（內容不變）
+    async fn reparent_wiki_page(&self, project_id: &str, title: &str, parent_title: &str) -> Result<()> {
+        let page = self.get_wiki_page(project_id, title).await?.wiki_page;
+        let params = WikiPageParams {
+            text: page.text,
+            comments: None,
+            version: Some(page.version),
+            parent_title: Some(parent_title.to_string()),
+        };
+        self.update_wiki_page(project_id, title, &params).await
+    }
+
     // ========== Files ==========
 
     /// 取得專案檔案
@@ -251,11 +625,66 @@ impl RedmineClient {
             .await
     }
 
+    /// 將已上傳的檔案加入專案檔案區（可關聯版本）
+    pub async fn create_project_file(&self, project_id: &str, params: &ProjectFileParams) -> Result<()> {
+        let body = serde_json::json!({ "file": params });
+        self.post_no_content(&format!("/projects/{}/files.json", project_id), &body)
+            .await
+    }
+
     /// 取得附件資訊
     pub async fn get_attachment(&self, id: u64) -> Result<AttachmentResponse> {
         self.get(&format!("/attachments/{}.json", id)).await
     }
 
+    /// 更新附件檔名與說明
+    pub async fn update_attachment(&self, id: u64, params: &AttachmentUpdateParams) -> Result<()> {
+        let body = serde_json::json!({ "attachment": params });
+        self.patch(&format!("/attachments/{}.json", id), &body).await
+    }
+
+    /// 刪除附件
+    pub async fn delete_attachment(&self, id: u64) -> Result<()> {
+        self.delete(&format!("/attachments/{}.json", id)).await
+    }
+
+    /// 取得附件縮圖（僅圖片附件；`size` 為像素邊長，預設由伺服器決定）
+    ///
+    /// 回應的 Content-Type 不是 `image/*` 時回傳 `RedmineError::UnsupportedContent`。
+    pub async fn get_attachment_thumbnail(&self, id: u64, size: Option<u32>) -> Result<BinaryContent> {
+        let url = match size {
+            Some(s) => format!("{}/attachments/thumbnail/{}/{}", self.base_url, id, s),
+            None => format!("{}/attachments/thumbnail/{}", self.base_url, id),
+        };
+        debug!("GET {}", url);
+        let response = self.send(self.client.get(&url)).await?;
+
+        if !response.status().is_success() {
+            return Err(match RedmineError::from_response(response).await {
+                RedmineError::NotFound { .. } => RedmineError::NotFound {
+                    resource: "Thumbnail".into(),
+                    id: id.to_string(),
+                },
+                e => e,
+            });
+        }
+
+        let content_type = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.split(';').next().unwrap_or(v).trim().to_string());
+        // 未登入或權限不足時部分伺服器回傳 HTML 頁面而非錯誤碼
+        if !content_type.as_deref().is_some_and(|t| t.starts_with("image/")) {
+            return Err(RedmineError::UnsupportedContent(format!(
+                "附件 #{id} 的縮圖回應不是圖片 ({})",
+                content_type.as_deref().unwrap_or("未提供 Content-Type")
+            )));
+        }
+        let data = response.bytes().await?;
+        Ok(BinaryContent { content_type, data })
+    }
+
     /// 上傳檔案
     pub async fn upload_file(&self, file_path: &str, description: Option<&str>) -> Result<UploadResponse> {
         let path = Path::new(file_path);
@@ -281,11 +710,12 @@ impl RedmineClient {
         }
 
         let response = self
-            .client
-            .post(&url)
-            .header(header::CONTENT_TYPE, "application/octet-stream")
-            .body(content)
-            .send()
+            .send(
+                self.client
+                    .post(&url)
+                    .header(header::CONTENT_TYPE, "application/octet-stream")
+                    .body(content),
+            )
             .await?;
 
         Self::handle_response(response).await
@@ -298,10 +728,7 @@ impl RedmineClient {
         let filename = info.attachment.filename.clone();
 
         let response = self
-            .client
-            .get(content_url)
-            .header("X-Redmine-API-Key", &self.api_key)
-            .send()
+            .send(self.client.get(content_url))
             .await?;
 
         if !response.status().is_success() {
@@ -317,66 +744,299 @@ impl RedmineClient {
         })
     }
 
+    /// 讀取附件內容（超過 `max_bytes` 時回傳 `RedmineError::TooLarge`，不會完整下載）
+    pub async fn get_attachment_content(
+        &self,
+        attachment_id: u64,
+        max_bytes: u64,
+    ) -> Result<(Attachment, BinaryContent)> {
+        let attachment = self.get_attachment(attachment_id).await?.attachment;
+        if attachment.filesize > max_bytes {
+            return Err(RedmineError::TooLarge {
+                name: attachment.filename,
+                size: attachment.filesize,
+                limit: max_bytes,
+            });
+        }
+
+        debug!("GET {}", attachment.content_url);
+        let mut response = self
+            .send(self.client.get(&attachment.content_url))
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(RedmineError::from_response(response).await);
+        }
+
+        let content_type = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.split(';').next().unwrap_or(v).trim().to_string());
+
+        // 伺服器回報的 filesize 不一定可信，邊讀邊檢查
+        let mut data = Vec::with_capacity(attachment.filesize as usize);
+        while let Some(chunk) = response.chunk().await? {
+            data.extend_from_slice(&chunk);
+            if data.len() as u64 > max_bytes {
+                return Err(RedmineError::TooLarge {
+                    name: attachment.filename,
+                    size: data.len() as u64,
+                    limit: max_bytes,
+                });
+            }
+        }
+
+        Ok((
+            attachment,
+            BinaryContent {
+                content_type,
+                data: data.into(),
+            },
+        ))
+    }
+
     // ========== Search ==========
 
     /// 全文搜尋
     ///
     /// `scope` 會自動轉換為 Redmine 的資源類型參數（issues=1 等）
     pub async fn search(&self, query: &str, params: &SearchParams) -> Result<SearchResponse> {
-        let mut full_params = params.clone();
-        full_params.q = Some(query.to_string());
-        // 把 scope（如 "issues"）轉為 Redmine 實際的篩選參數
-        if let Some(scope) = &full_params.scope {
-            for s in scope.split(',') {
-                match s.trim() {
-                    "issues" => full_params.issues = Some(1),
-                    "news" => full_params.news = Some(1),
-                    "documents" => full_params.documents = Some(1),
-                    "changesets" => full_params.changesets = Some(1),
-                    "wiki_pages" | "wiki" => full_params.wiki_pages = Some(1),
-                    "messages" => full_params.messages = Some(1),
-                    "projects" => full_params.projects = Some(1),
-                    _ => {} // 忽略不認識的 scope
-                }
-            }
-            full_params.scope = None;
-        }
-        self.get_with_query("/search.json", &full_params).await
+        self.get_with_query("/search.json", &search_params(query, params))
+            .await
     }
 
     // ========== Others ==========
 
-    /// 取得已存查詢
+    /// 取得已存查詢（自動分頁）
     pub async fn get_queries(&self) -> Result<QueryListResponse> {
-        self.get("/queries.json").await
+        let (queries, total_count) = self
+            .fetch_all::<QueryListResponse>("/queries.json", Vec::new(), Some(MAX_ITEMS_LIMIT))
+            .await?;
+        Ok(QueryListResponse { queries, total_count })
+    }
+
+    /// 以名稱（不分大小寫）或 ID 找出已儲存查詢
+    ///
+    /// 指定 `project` 時只考慮該專案與全域查詢，同名時專案查詢優先。
+    pub async fn find_query(&self, name_or_id: &str, project: Option<&str>) -> Result<SavedQuery> {
+        let queries = self.get_queries().await?.queries;
+        if let Ok(id) = name_or_id.parse::<u64>() {
+            if let Some(q) = queries.iter().find(|q| q.id == id) {
+                return Ok(q.clone());
+            }
+        }
+        let project_id = match project {
+            Some(p) => Some(self.get_project(p).await?.project.id),
+            None => None,
+        };
+        let mut matches: Vec<&SavedQuery> = queries
+            .iter()
+            .filter(|q| q.name.eq_ignore_ascii_case(name_or_id))
+            .filter(|q| project_id.is_none() || q.project_id.is_none() || q.project_id == project_id)
+            .collect();
+        matches.sort_by_key(|q| q.project_id.is_none());
+        matches
+            .first()
+            .map(|q| (*q).clone())
+            .ok_or_else(|| RedmineError::NotFound {
+                resource: "Query".into(),
+                id: name_or_id.to_string(),
+            })
+    }
+
+    /// 將 `query` 解析為 query_id 填入參數；專案查詢未指定專案時自動補上
+    ///
+    /// 數字 ID 直接使用；未指定專案時才查詢列表補上專案（列表中沒有此 ID 時不補，取得列表失敗則回傳錯誤）。
+    pub async fn apply_saved_query(&self, params: &mut IssueListParams, query: &str) -> Result<()> {
+        if let Ok(id) = query.parse::<u64>() {
+            params.query_id = Some(id);
+            if params.project_id.is_none() {
+                let queries = self.get_queries().await?.queries;
+                params.project_id = queries
+                    .iter()
+                    .find(|q| q.id == id)
+                    .and_then(|q| q.project_id)
+                    .map(|id| id.to_string());
+            }
+            return Ok(());
+        }
+        let saved = self.find_query(query, params.project_id.as_deref()).await?;
+        params.query_id = Some(saved.id);
+        if params.project_id.is_none() {
+            params.project_id = saved.project_id.map(|id| id.to_string());
+        }
+        Ok(())
     }
 
     /// 取得角色列表
     pub async fn get_roles(&self) -> Result<RoleListResponse> {
-        self.get("/roles.json").await
+        self.get_cached("/roles.json").await
     }
 
+    /// 取得自訂欄位定義（需管理員權限）
+    pub async fn get_custom_fields(&self) -> Result<CustomFieldListResponse> {
+        self.get_cached("/custom_fields.json").await
+    }
+
+    /// 取得角色詳情（含權限）
+    pub async fn get_role(&self, id: u64) -> Result<RoleResponse> {
+        self.get_cached(&format!("/roles/{}.json", id)).await
+    }
+
+    // ========== Permissions ==========
+
+    /// 彙整目前使用者的有效權限：成員角色的權限，過濾掉所屬模組未啟用的部分
+    ///
+    /// 指定 `project` 時只回傳該專案；非成員的專案回傳空角色。
+    pub async fn get_my_permissions(&self, project: Option<&str>) -> Result<MyPermissions> {
+        let user = self
+            .get::<UserResponse>("/users/current.json?include=memberships")
+            .await?
+            .user;
+        let memberships = user.memberships.clone().unwrap_or_default();
+
+        let targets: Vec<(u64, Vec<IdName>)> = match project {
+            Some(p) => {
+                let id = self.get_project(p).await?.project.id;
+                let roles = memberships
+                    .iter()
+                    .filter(|m| m.project.id == id)
+                    .flat_map(|m| m.roles.clone())
+                    .collect();
+                vec![(id, roles)]
+            }
+            None => memberships
+                .iter()
+                .map(|m| (m.project.id, m.roles.clone()))
+                .collect(),
+        };
+
+        let mut role_cache: HashMap<u64, Role> = Default::default();
+        let mut projects = Vec::new();
+        for (project_id, roles) in targets {
+            let project = self.get_project(&project_id.to_string()).await?.project;
+            let enabled_modules: Vec<String> = project
+                .enabled_modules
+                .unwrap_or_default()
+                .into_iter()
+                .map(|m| m.name)
+                .collect();
+
+            let mut permissions = BTreeSet::new();
+            for role in &roles {
+                if let Entry::Vacant(entry) = role_cache.entry(role.id) {
+                    entry.insert(self.get_role(role.id).await?.role);
+                }
+                permissions.extend(
+                    role_cache[&role.id]
+                        .permissions
+                        .iter()
+                        .filter(|p| {
+                            permission_module(p)
+                                .is_none_or(|m| enabled_modules.iter().any(|e| e == m))
+                        })
+                        .cloned(),
+                );
+            }
+
+            projects.push(ProjectPermissions {
+                project: IdName {
+                    id: project.id,
+                    name: project.name,
+                },
+                roles: roles.into_iter().map(|r| r.name).collect(),
+                enabled_modules,
+                permissions: permissions.into_iter().collect(),
+            });
+        }
+
+        Ok(MyPermissions {
+            user_id: user.id,
+            login: user.login,
+            admin: user.admin.unwrap_or(false),
+            projects,
+        })
+    }
+
+    // ========== Groups ==========
+
     /// 取得群組列表
     pub async fn get_groups(&self) -> Result<GroupListResponse> {
         self.get("/groups.json").await
     }
 
+    /// 取得群組詳情（含成員與專案成員資格）
+    pub async fn get_group(&self, id: u64) -> Result<GroupResponse> {
+        self.get(&format!("/groups/{}.json?include=users,memberships", id))
+            .await
+    }
+
+    /// 建立群組（需管理員權限）
+    pub async fn create_group(&self, params: &GroupParams) -> Result<GroupResponse> {
+        let body = serde_json::json!({ "group": params });
+        self.post("/groups.json", &body).await
+    }
+
+    /// 更新群組（需管理員權限；`user_ids` 會取代全部成員）
+    pub async fn update_group(&self, id: u64, params: &GroupParams) -> Result<()> {
+        let body = serde_json::json!({ "group": params });
+        self.put(&format!("/groups/{}.json", id), &body).await
+    }
+
+    /// 刪除群組（需管理員權限）
+    pub async fn delete_group(&self, id: u64) -> Result<()> {
+        self.delete(&format!("/groups/{}.json", id)).await
+    }
+
+    // ========== News ==========
+
     /// 取得新聞
-    pub async fn get_news(&self, project_id: Option<&str>) -> Result<NewsListResponse> {
+    pub async fn get_news(
+        &self,
+        project_id: Option<&str>,
+        params: &NewsListParams,
+    ) -> Result<NewsListResponse> {
         let path = match project_id {
             Some(id) => format!("/projects/{}/news.json", id),
             None => "/news.json".to_string(),
         };
-        self.get(&path).await
+        self.get_with_query(&path, params).await
+    }
+
+    /// 取得單一新聞（含留言）
+    pub async fn get_news_item(&self, id: u64) -> Result<NewsResponse> {
+        self.get(&format!("/news/{}.json?include=comments", id))
+            .await
+    }
+
+    /// 建立新聞
+    pub async fn create_news(&self, project_id: &str, params: &NewsParams) -> Result<()> {
+        let body = serde_json::json!({ "news": params });
+        self.post_no_content(&format!("/projects/{}/news.json", project_id), &body)
+            .await
+    }
+
+    /// 更新新聞
+    pub async fn update_news(&self, id: u64, params: &NewsParams) -> Result<()> {
+        let body = serde_json::json!({ "news": params });
+        self.put(&format!("/news/{}.json", id), &body).await
+    }
+
+    /// 刪除新聞
+    pub async fn delete_news(&self, id: u64) -> Result<()> {
+        self.delete(&format!("/news/{}.json", id)).await
     }
 
+    // ========== Generic ==========
+
     /// 通用 API 請求
     pub async fn request(
         &self,
         path: &str,
         method: &str,
         data: Option<&serde_json::Value>,
-        params: Option<&std::collections::HashMap<String, String>>,
+        params: Option<&HashMap<String, String>>,
     ) -> Result<GenericResponse> {
         let normalized_path = if path.starts_with('/') {
             path.to_string()
@@ -413,7 +1073,7 @@ impl RedmineClient {
             }
         }
 
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         let status_code = response.status().as_u16();
         let is_success = response.status().is_success();
 
@@ -442,10 +1102,24 @@ impl RedmineClient {
     async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
         let url = format!("{}{}", self.base_url, path);
         debug!("GET {}", url);
-        let response = self.client.get(&url).send().await?;
+        let response = self.send(self.client.get(&url)).await?;
         Self::handle_response(response).await
     }
 
+    /// GET 並透過中繼資料快取
+    async fn get_cached<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        if let Some(data) = self.cache.get(path) {
+            if let Ok(result) = serde_json::from_value(data) {
+                debug!("GET {} (cached)", path);
+                return Ok(result);
+            }
+        }
+        let data: serde_json::Value = self.get(path).await?;
+        let result = serde_json::from_value(data.clone())?;
+        self.cache.insert(path, data);
+        Ok(result)
+    }
+
     async fn get_with_query<T: DeserializeOwned, Q: Serialize>(
         &self,
         path: &str,
@@ -460,21 +1134,46 @@ impl RedmineClient {
             format!("{}?{}", base_url, query_string)
         };
         debug!("GET {}", url);
-        let response = self.client.get(&url).send().await?;
+        let response = self.send(self.client.get(&url)).await?;
         Self::handle_response(response).await
     }
 
     async fn post<T: DeserializeOwned, B: Serialize>(&self, path: &str, body: &B) -> Result<T> {
         let url = format!("{}{}", self.base_url, path);
         debug!("POST {}", url);
-        let response = self.client.post(&url).json(body).send().await?;
+        let response = self.send(self.client.post(&url).json(body)).await?;
         Self::handle_response(response).await
     }
 
+    /// POST 但不解析回應內容（Redmine 部分端點成功時回傳 204）
+    async fn post_no_content<B: Serialize>(&self, path: &str, body: &B) -> Result<()> {
+        let url = format!("{}{}", self.base_url, path);
+        debug!("POST {}", url);
+        let response = self.send(self.client.post(&url).json(body)).await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(RedmineError::from_response(response).await)
+        }
+    }
+
     async fn put<B: Serialize>(&self, path: &str, body: &B) -> Result<()> {
         let url = format!("{}{}", self.base_url, path);
         debug!("PUT {}", url);
-        let response = self.client.put(&url).json(body).send().await?;
+        let response = self.send(self.client.put(&url).json(body)).await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(RedmineError::from_response(response).await)
+        }
+    }
+
+    async fn patch<B: Serialize>(&self, path: &str, body: &B) -> Result<()> {
+        let url = format!("{}{}", self.base_url, path);
+        debug!("PATCH {}", url);
+        let response = self.send(self.client.patch(&url).json(body)).await?;
 
         if response.status().is_success() {
             Ok(())
@@ -486,7 +1185,7 @@ impl RedmineClient {
     async fn delete(&self, path: &str) -> Result<()> {
         let url = format!("{}{}", self.base_url, path);
         debug!("DELETE {}", url);
-        let response = self.client.delete(&url).send().await?;
+        let response = self.send(self.client.delete(&url)).await?;
 
         if response.status().is_success() {
             Ok(())
@@ -512,3 +1211,34 @@ impl RedmineClient {
         }
     }
 }
+
+/// `/issues.json` 的查詢參數；有進階篩選或已儲存查詢時改用 `f[]/op[]/v[]` 語法（並檢查兩者不能並用）
+fn issue_query_pairs(params: &IssueListParams) -> Result<Vec<(String, String)>> {
+    if params.filters.is_empty() && params.query_id.is_none() {
+        paginate::query_pairs(params)
+    } else {
+        params.filters.query_pairs(params)
+    }
+}
+
+/// 組出搜尋參數，並把 scope（如 "issues"）轉為 Redmine 實際的篩選參數
+fn search_params(query: &str, params: &SearchParams) -> SearchParams {
+    let mut full_params = params.clone();
+    full_params.q = Some(query.to_string());
+    if let Some(scope) = &full_params.scope {
+        for s in scope.split(',') {
+            match s.trim() {
+                "issues" => full_params.issues = Some(1),
+                "news" => full_params.news = Some(1),
+                "documents" => full_params.documents = Some(1),
+                "changesets" => full_params.changesets = Some(1),
+                "wiki_pages" | "wiki" => full_params.wiki_pages = Some(1),
+                "messages" => full_params.messages = Some(1),
+                "projects" => full_params.projects = Some(1),
+                _ => {} // 忽略不認識的 scope
+            }
+        }
+        full_params.scope = None;
+    }
+    full_params
+}
@@ -0,0 +1,230 @@
+//! 自動分頁 — 依 `total_count` 逐頁取得列表端點的所有項目
+//!
+//! 單頁最多 100 筆（Redmine 上限）；為避免一次拉下整個資料庫，
+//! 全部取得時預設最多 [`DEFAULT_MAX_ITEMS`] 筆，明確指定也不超過 [`MAX_ITEMS_LIMIT`]。
+
+use super::*;
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+
+/// Redmine 單頁上限
+pub const PAGE_SIZE: u64 = 100;
+/// 全部取得時的預設筆數上限
+pub const DEFAULT_MAX_ITEMS: u64 = 1_000;
+/// 全部取得時的絕對上限
+pub const MAX_ITEMS_LIMIT: u64 = 10_000;
+
+/// 帶 `total_count` 的分頁列表回應
+pub trait Paged: DeserializeOwned + Send {
+    type Item: Send;
+
+    fn total_count(&self) -> u64;
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+macro_rules! impl_paged {
+    ($response:ty, $field:ident, $item:ty) => {
+        impl Paged for $response {
+            type Item = $item;
+
+            fn total_count(&self) -> u64 {
+                self.total_count
+            }
+
+            fn into_items(self) -> Vec<$item> {
+                self.$field
+            }
+        }
+    };
+}
+
+impl_paged!(IssueListResponse, issues, Issue);
+impl_paged!(UserListResponse, users, User);
+impl_paged!(TimeEntryListResponse, time_entries, TimeEntry);
+impl_paged!(ProjectListResponse, projects, Project);
+impl_paged!(MembershipListResponse, memberships, Membership);
+impl_paged!(SearchResponse, results, SearchResult);
+impl_paged!(QueryListResponse, queries, SavedQuery);
+
+/// 將 `max_items` 限制在安全範圍內
+pub fn clamp_max_items(max_items: Option<u64>) -> u64 {
+    max_items.unwrap_or(DEFAULT_MAX_ITEMS).clamp(1, MAX_ITEMS_LIMIT)
+}
+
+/// 將可序列化的查詢參數轉為 key/value 列表
+pub(super) fn query_pairs<Q: Serialize>(query: &Q) -> Result<Vec<(String, String)>> {
+    let encoded = serde_urlencoded::to_string(query)
+        .map_err(|e| RedmineError::Config(format!("Failed to encode query: {}", e)))?;
+    serde_urlencoded::from_str(&encoded)
+        .map_err(|e| RedmineError::Config(format!("Failed to encode query: {}", e)))
+}
+
+fn without_paging(query: Vec<(String, String)>) -> Vec<(String, String)> {
+    query
+        .into_iter()
+        .filter(|(k, _)| k != "limit" && k != "offset")
+        .collect()
+}
+
+/// 逐頁狀態
+struct PageState {
+    offset: u64,
+    remaining: u64,
+    done: bool,
+}
+
+impl RedmineClient {
+    /// 逐項產生列表端點的所有項目（最多 `max_items` 筆）
+    ///
+    /// `query` 中的 `limit`/`offset` 會被忽略，由分頁邏輯控制。
+    pub fn paginate<'a, R>(
+        &'a self,
+        path: &'a str,
+        query: Vec<(String, String)>,
+        max_items: u64,
+    ) -> BoxStream<'a, Result<R::Item>>
+    where
+        R: Paged + 'a,
+    {
+        self.paginate_from::<R>(path, query, 0, max_items)
+    }
+
+    fn paginate_from<'a, R>(
+        &'a self,
+        path: &'a str,
+        query: Vec<(String, String)>,
+        offset: u64,
+        max_items: u64,
+    ) -> BoxStream<'a, Result<R::Item>>
+    where
+        R: Paged + 'a,
+    {
+        let query = without_paging(query);
+        let state = PageState {
+            offset,
+            remaining: max_items,
+            done: false,
+        };
+
+        stream::try_unfold(state, move |state| {
+            let mut query = query.clone();
+            async move {
+                if state.done || state.remaining == 0 {
+                    return Result::Ok(None);
+                }
+                let limit = state.remaining.min(PAGE_SIZE);
+                query.push(("limit".into(), limit.to_string()));
+                query.push(("offset".into(), state.offset.to_string()));
+                let page: R = self.get_with_query(path, &query).await?;
+
+                let total = page.total_count();
+                let items = page.into_items();
+                let fetched = items.len() as u64;
+                let offset = state.offset + fetched;
+                let next = PageState {
+                    offset,
+                    remaining: state.remaining.saturating_sub(fetched),
+                    done: fetched == 0 || offset >= total,
+                };
+                Ok(Some((stream::iter(items.into_iter().map(Ok)), next)))
+            }
+        })
+        .try_flatten()
+        .boxed()
+    }
+
+    /// 取得全部項目與伺服器回報的總數
+    pub(super) async fn fetch_all<R: Paged>(
+        &self,
+        path: &str,
+        query: Vec<(String, String)>,
+        max_items: Option<u64>,
+    ) -> Result<(Vec<R::Item>, u64)> {
+        let max_items = clamp_max_items(max_items);
+        let query = without_paging(query);
+
+        // 先取第一頁得到 total_count，其餘交給 paginate
+        let mut first_query = query.clone();
+        first_query.push(("limit".into(), max_items.min(PAGE_SIZE).to_string()));
+        let first: R = self.get_with_query(path, &first_query).await?;
+        let total = first.total_count();
+        let mut items = first.into_items();
+
+        let fetched = items.len() as u64;
+        if fetched > 0 && fetched < total && fetched < max_items {
+            let rest: Vec<R::Item> = self
+                .paginate_from::<R>(path, query, fetched, max_items - fetched)
+                .try_collect()
+                .await?;
+            items.extend(rest);
+        }
+        if (items.len() as u64) < total {
+            debug!("{} 取得 {}/{} 筆（max_items={}）", path, items.len(), total, max_items);
+        }
+        Ok((items, total))
+    }
+
+    /// 取得所有符合條件的 Issue（`max_items` 為安全上限）
+    pub async fn get_all_issues(
+        &self,
+        params: &IssueListParams,
+        max_items: Option<u64>,
+    ) -> Result<IssueListResponse> {
+        let (issues, total_count) = self
+            .fetch_all::<IssueListResponse>("/issues.json", issue_query_pairs(params)?, max_items)
+            .await?;
+        Ok(IssueListResponse {
+            limit: issues.len() as u64,
+            issues,
+            total_count,
+            offset: 0,
+        })
+    }
+
+    /// 取得所有符合條件的使用者
+    pub async fn get_all_users(
+        &self,
+        params: &UserListParams,
+        max_items: Option<u64>,
+    ) -> Result<UserListResponse> {
+        let (users, total_count) = self
+            .fetch_all::<UserListResponse>("/users.json", query_pairs(params)?, max_items)
+            .await?;
+        Ok(UserListResponse { users, total_count })
+    }
+
+    /// 取得所有符合條件的工時
+    pub async fn get_all_time_entries(
+        &self,
+        params: &TimeEntryListParams,
+        max_items: Option<u64>,
+    ) -> Result<TimeEntryListResponse> {
+        let (time_entries, total_count) = self
+            .fetch_all::<TimeEntryListResponse>("/time_entries.json", query_pairs(params)?, max_items)
+            .await?;
+        Ok(TimeEntryListResponse {
+            limit: time_entries.len() as u64,
+            time_entries,
+            total_count,
+            offset: 0,
+        })
+    }
+
+    /// 取得所有搜尋結果
+    pub async fn search_all(
+        &self,
+        query: &str,
+        params: &SearchParams,
+        max_items: Option<u64>,
+    ) -> Result<SearchResponse> {
+        let full_params = search_params(query, params);
+        let (results, total_count) = self
+            .fetch_all::<SearchResponse>("/search.json", query_pairs(&full_params)?, max_items)
+            .await?;
+        Ok(SearchResponse {
+            limit: results.len() as u64,
+            results,
+            total_count,
+            offset: 0,
+        })
+    }
+}
@@ -0,0 +1,59 @@
+//! 權限與專案模組的對應
+//!
+//! Redmine 角色的權限只有在對應模組啟用時才生效；API 不提供這個對應，
+//! 以下依 Redmine 5 核心模組整理。未列出的權限（專案層級或外掛）視為不受模組限制。
+
+use super::IdName;
+use serde::{Deserialize, Serialize};
+
+/// 權限所屬的專案模組
+pub fn permission_module(permission: &str) -> Option<&'static str> {
+    let module = match permission {
+        "view_issues" | "add_issues" | "edit_issues" | "edit_own_issues" | "copy_issues"
+        | "manage_issue_relations" | "manage_subtasks" | "set_issues_private"
+        | "set_own_issues_private" | "add_issue_notes" | "edit_issue_notes"
+        | "edit_own_issue_notes" | "view_private_notes" | "set_notes_private"
+        | "delete_issues" | "view_issue_watchers" | "add_issue_watchers"
+        | "delete_issue_watchers" | "import_issues" | "manage_categories" => "issue_tracking",
+        "view_time_entries" | "log_time" | "edit_time_entries" | "edit_own_time_entries"
+        | "manage_project_activities" | "log_time_for_other_users" | "import_time_entries" => {
+            "time_tracking"
+        }
+        "view_news" | "manage_news" | "comment_news" => "news",
+        "view_documents" | "add_documents" | "edit_documents" | "delete_documents" => "documents",
+        "view_files" | "manage_files" => "files",
+        "view_wiki_pages" | "view_wiki_edits" | "export_wiki_pages" | "edit_wiki_pages"
+        | "rename_wiki_pages" | "delete_wiki_pages" | "delete_wiki_pages_attachments"
+        | "view_wiki_page_watchers" | "add_wiki_page_watchers" | "delete_wiki_page_watchers"
+        | "protect_wiki_pages" | "manage_wiki" => "wiki",
+        "view_changesets" | "browse_repository" | "commit_access" | "manage_related_issues"
+        | "manage_repository" => "repository",
+        "view_messages" | "add_messages" | "edit_messages" | "edit_own_messages"
+        | "delete_messages" | "delete_own_messages" | "view_message_watchers"
+        | "add_message_watchers" | "delete_message_watchers" | "manage_boards" => "boards",
+        "view_calendar" => "calendar",
+        "view_gantt" => "gantt",
+        _ => return None,
+    };
+    Some(module)
+}
+
+/// 目前使用者在單一專案的有效權限
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectPermissions {
+    pub project: IdName,
+    pub roles: Vec<String>,
+    pub enabled_modules: Vec<String>,
+    /// 角色權限中所屬模組已啟用的部分
+    pub permissions: Vec<String>,
+}
+
+/// 目前使用者（API token）的權限總覽
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MyPermissions {
+    pub user_id: u64,
+    pub login: String,
+    /// 管理員不受角色限制，擁有所有權限
+    pub admin: bool,
+    pub projects: Vec<ProjectPermissions>,
+}
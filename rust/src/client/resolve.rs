@@ -0,0 +1,305 @@
+//! 名稱解析 — 把名稱、登入帳號、`me` 或專案識別碼轉為 Redmine ID
+//!
+//! 比對順序：數字直接視為 ID → 不分大小寫完全相符 → 唯一的部分相符。
+//! 同一層有多個候選時回傳 [`RedmineError::Ambiguous`] 並列出候選。
+
+use super::*;
+use futures::stream::{BoxStream, TryStreamExt};
+
+/// 解析候選
+struct Candidate {
+    id: u64,
+    label: String,
+    keys: Vec<String>,
+    /// 伺服器保證唯一的鍵（如專案識別碼），完全相符即可停止分頁
+    unique_key: Option<String>,
+}
+
+impl Candidate {
+    fn new(id: u64, label: &str) -> Self {
+        Self {
+            id,
+            label: label.to_string(),
+            keys: vec![label.to_string()],
+            unique_key: None,
+        }
+    }
+
+    fn with_key(mut self, key: &str) -> Self {
+        self.keys.push(key.to_string());
+        self
+    }
+
+    fn with_unique_key(mut self, key: &str) -> Self {
+        self.unique_key = Some(key.to_string());
+        self.with_key(key)
+    }
+}
+
+/// 從候選中找出唯一符合的 ID
+fn pick(kind: &str, input: &str, candidates: Vec<Candidate>) -> Result<u64> {
+    let input = input.trim();
+    let needle = input.to_lowercase();
+
+    let exact: Vec<&Candidate> = candidates
+        .iter()
+        .filter(|c| c.keys.iter().any(|k| k.to_lowercase() == needle))
+        .collect();
+    let matches = if exact.is_empty() {
+        candidates
+            .iter()
+            .filter(|c| c.keys.iter().any(|k| k.to_lowercase().contains(&needle)))
+            .collect()
+    } else {
+        exact
+    };
+
+    let mut ids: Vec<u64> = matches.iter().map(|c| c.id).collect();
+    ids.sort_unstable();
+    ids.dedup();
+    match ids.as_slice() {
+        [id] => Ok(*id),
+        [] => Err(RedmineError::NotFound {
+            resource: kind.to_string(),
+            id: input.to_string(),
+        }),
+        _ => Err(RedmineError::Ambiguous {
+            kind: kind.to_string(),
+            input: input.to_string(),
+            candidates: matches.iter().map(|c| format!("{} (#{})", c.label, c.id)).collect(),
+        }),
+    }
+}
+
+/// 逐項讀取分頁列表的候選，唯一鍵完全相符時停止分頁
+///
+/// 名稱可能重複，名稱相符時仍須讀完全部候選，才能在重名時回報 [`RedmineError::Ambiguous`]。
+async fn collect_until_unique<T>(
+    input: &str,
+    mut items: BoxStream<'_, Result<T>>,
+    to_candidate: impl Fn(T) -> Option<Candidate>,
+) -> Result<Vec<Candidate>> {
+    let needle = input.trim().to_lowercase();
+    let mut candidates = Vec::new();
+    while let Some(item) = items.try_next().await? {
+        let Some(candidate) = to_candidate(item) else { continue };
+        if candidate.unique_key.as_ref().is_some_and(|k| k.to_lowercase() == needle) {
+            return Ok(vec![candidate]);
+        }
+        candidates.push(candidate);
+    }
+    Ok(candidates)
+}
+
+/// 數字直接視為 ID，否則載入候選後比對
+async fn resolve_named<F, Fut>(kind: &str, input: &str, load: F) -> Result<u64>
+where
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = Result<Vec<Candidate>>>,
+{
+    match input.trim().parse::<u64>() {
+        Ok(id) => Ok(id),
+        Err(_) => pick(kind, input, load().await?),
+    }
+}
+
+/// 看起來像專案識別碼（小寫英數、`-`、`_`，以字母開頭）
+fn is_project_identifier(input: &str) -> bool {
+    input.chars().next().is_some_and(|c| c.is_ascii_lowercase())
+        && input
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+}
+
+impl RedmineClient {
+    /// 專案：ID 或識別碼原樣回傳，其他以專案名稱解析為 ID
+    pub async fn resolve_project(&self, input: &str) -> Result<String> {
+        let input = input.trim();
+        if input.parse::<u64>().is_ok() || is_project_identifier(input) {
+            return Ok(input.to_string());
+        }
+        let projects = self.paginate::<ProjectListResponse>("/projects.json", Vec::new(), MAX_ITEMS_LIMIT);
+        let candidates = collect_until_unique(input, projects, |p| {
+            Some(Candidate::new(p.id, &p.name).with_unique_key(&p.identifier))
+        })
+        .await?;
+        pick("Project", input, candidates).map(|id| id.to_string())
+    }
+
+    /// 使用者：`me`、ID、登入帳號、姓名或 email
+    ///
+    /// 指定 `project` 時從專案成員中比對（不需管理員權限）；否則查詢 `/users.json`。
+    pub async fn resolve_user(&self, input: &str, project: Option<&str>) -> Result<u64> {
+        let input = input.trim();
+        if input.eq_ignore_ascii_case("me") {
+            return Ok(self.get_current_user().await?.user.id);
+        }
+        if let Ok(id) = input.parse::<u64>() {
+            return Ok(id);
+        }
+        let candidates = match project {
+            Some(project) => {
+                let path = format!("/projects/{}/memberships.json", project);
+                let members = self.paginate::<MembershipListResponse>(&path, Vec::new(), MAX_ITEMS_LIMIT);
+                collect_until_unique(input, members, |m| m.user.map(|u| Candidate::new(u.id, &u.name))).await?
+            }
+            None => {
+                let params = UserListParams {
+                    name: Some(input.to_string()),
+                    limit: Some(PAGE_SIZE),
+                    ..Default::default()
+                };
+                self.get_users(&params)
+                    .await?
+                    .users
+                    .iter()
+                    .map(|u| {
+                        let name = format!("{} {}", u.firstname, u.lastname);
+                        let mut c = Candidate::new(u.id, &name)
+                            .with_key(&u.login)
+                            .with_key(&format!("{} {}", u.lastname, u.firstname))
+                            .with_key(&format!("{}{}", u.lastname, u.firstname));
+                        if let Some(mail) = &u.mail {
+                            c = c.with_key(mail);
+                        }
+                        c.label = format!("{name} <{}>", u.login);
+                        c
+                    })
+                    .collect()
+            }
+        };
+        pick("User", input, candidates)
+    }
+
+    /// Issue 狀態名稱
+    pub async fn resolve_status(&self, input: &str) -> Result<u64> {
+        resolve_named("Status", input, || async {
+            Ok(self
+                .get_statuses()
+                .await?
+                .issue_statuses
+                .iter()
+                .map(|s| Candidate::new(s.id, &s.name))
+                .collect())
+        })
+        .await
+    }
+
+    /// Tracker 名稱
+    pub async fn resolve_tracker(&self, input: &str) -> Result<u64> {
+        resolve_named("Tracker", input, || async {
+            Ok(self
+                .get_trackers()
+                .await?
+                .trackers
+                .iter()
+                .map(|t| Candidate::new(t.id, &t.name))
+                .collect())
+        })
+        .await
+    }
+
+    /// 優先權名稱
+    pub async fn resolve_priority(&self, input: &str) -> Result<u64> {
+        resolve_named("Priority", input, || async {
+            Ok(self
+                .get_priorities()
+                .await?
+                .issue_priorities
+                .iter()
+                .map(|p| Candidate::new(p.id, &p.name))
+                .collect())
+        })
+        .await
+    }
+
+    /// 工時活動名稱
+    pub async fn resolve_activity(&self, input: &str) -> Result<u64> {
+        resolve_named("Activity", input, || async {
+            Ok(self
+                .get_time_entry_activities()
+                .await?
+                .time_entry_activities
+                .iter()
+                .map(|a| Candidate::new(a.id, &a.name))
+                .collect())
+        })
+        .await
+    }
+
+    /// 群組名稱
+    pub async fn resolve_group(&self, input: &str) -> Result<u64> {
+        resolve_named("Group", input, || async {
+            Ok(self
+                .get_groups()
+                .await?
+                .groups
+                .iter()
+                .map(|g| Candidate::new(g.id, &g.name))
+                .collect())
+        })
+        .await
+    }
+
+    /// 列表篩選用的狀態：`open`、`closed`、`*` 原樣保留，其他以名稱解析（`!` 開頭表示排除）
+    pub async fn resolve_status_filter(&self, input: &str) -> Result<String> {
+        let (negate, name) = match input.strip_prefix('!') {
+            Some(rest) => ("!", rest),
+            None => ("", input),
+        };
+        if matches!(name, "open" | "closed" | "*" | "o" | "c" | "") {
+            return Ok(input.to_string());
+        }
+        Ok(format!("{negate}{}", self.resolve_status(name).await?))
+    }
+
+    /// 列表篩選用的使用者：`me` 原樣保留（由伺服器解析），其他解析為 ID
+    pub async fn resolve_user_filter(&self, input: &str, project: Option<&str>) -> Result<String> {
+        let (negate, name) = match input.strip_prefix('!') {
+            Some(rest) => ("!", rest),
+            None => ("", input),
+        };
+        if name.eq_ignore_ascii_case("me") {
+            return Ok(input.to_string());
+        }
+        Ok(format!("{negate}{}", self.resolve_user(name, project).await?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream::{self, StreamExt};
+
+    fn project(id: u64, name: &str, identifier: &str) -> Result<Candidate> {
+        Ok(Candidate::new(id, name).with_unique_key(identifier))
+    }
+
+    #[tokio::test]
+    async fn duplicate_names_are_ambiguous() {
+        let items = stream::iter(vec![
+            project(1, "Backend API", "api"),
+            project(2, "Backend API", "api-v2"),
+            project(3, "Frontend", "frontend"),
+        ])
+        .boxed();
+        let candidates = collect_until_unique("Backend API", items, Some).await.unwrap();
+        assert_eq!(candidates.len(), 3);
+        assert!(matches!(
+            pick("Project", "Backend API", candidates),
+            Err(RedmineError::Ambiguous { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn unique_key_stops_paging() {
+        let items = stream::iter(vec![
+            project(1, "Backend", "backend"),
+            project(2, "Legacy", "legacy"),
+            Err(RedmineError::Forbidden),
+        ])
+        .boxed();
+        let candidates = collect_until_unique("Legacy", items, Some).await.unwrap();
+        assert_eq!(pick("Project", "Legacy", candidates).unwrap(), 2);
+    }
+}
@@ -0,0 +1,231 @@
+//! 重試與退避 — 處理反向代理偶發的 502/503/504、連線中斷與逾時
+//!
+//! 冪等方法（GET/PUT/DELETE/HEAD）遇到暫時性錯誤時以指數退避加隨機抖動重試；
+//! 429 對所有方法都重試（請求未被處理）。429/503 帶 `Retry-After` 時依其等待。
+
+use super::*;
+use reqwest::{Method, RequestBuilder, StatusCode};
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// 重試設定
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// 最多重試次數（0 停用）
+    pub max_retries: u32,
+    /// 第一次重試前的等待時間，之後每次加倍
+    pub base_delay: Duration,
+    /// 單次等待上限（也限制 `Retry-After`）
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// 第 `attempt` 次重試的退避時間：`base * 2^attempt` 取上限後，在後半段隨機抖動
+    fn backoff(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let half = delay / 2;
+        let jitter = random_u64() % (half.as_millis() as u64 + 1);
+        half + Duration::from_millis(jitter)
+    }
+
+    /// 決定是否重試；回傳下次請求前的等待時間
+    fn retry_delay(
+        &self,
+        attempt: u32,
+        idempotent: bool,
+        outcome: &reqwest::Result<Response>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_retries {
+            return None;
+        }
+        match outcome {
+            Ok(response) => self.status_delay(attempt, idempotent, response.status(), response.headers()),
+            // 連線失敗時請求尚未送出，任何方法都可重試
+            Err(e) if e.is_connect() => Some(self.backoff(attempt)),
+            Err(e) if idempotent && (e.is_timeout() || e.is_request()) => Some(self.backoff(attempt)),
+            Err(_) => None,
+        }
+    }
+
+    /// 依回應狀態決定是否重試：429 任何方法都重試，502/503/504 只重試冪等方法
+    fn status_delay(
+        &self,
+        attempt: u32,
+        idempotent: bool,
+        status: StatusCode,
+        headers: &header::HeaderMap,
+    ) -> Option<Duration> {
+        let retryable = status == StatusCode::TOO_MANY_REQUESTS
+            || (idempotent
+                && matches!(
+                    status,
+                    StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT
+                ));
+        if !retryable {
+            return None;
+        }
+        let retry_after = matches!(status, StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE)
+            .then(|| retry_after(headers))
+            .flatten();
+        Some(match retry_after {
+            Some(delay) => delay.min(self.max_delay),
+            None => self.backoff(attempt),
+        })
+    }
+}
+
+impl RedmineClient {
+    /// 送出請求，依 [`RetryPolicy`] 重試暫時性錯誤
+    pub(super) async fn send(&self, mut request: RequestBuilder) -> Result<Response> {
+        if let Some(user) = &self.switch_user {
+            request = request.header("X-Redmine-Switch-User", user);
+        }
+        let request = request.build()?;
+        if let Some(user) = &self.switch_user {
+            info!(target: "audit", "[audit] {} {} as {}", request.method(), request.url(), user);
+        }
+        let idempotent = matches!(
+            *request.method(),
+            Method::GET | Method::HEAD | Method::PUT | Method::DELETE
+        );
+
+        let mut attempt = 0;
+        loop {
+            // 串流 body 無法複製，只能送一次
+            let Some(current) = request.try_clone() else {
+                return Ok(self.client.execute(request).await?);
+            };
+            let outcome = self.client.execute(current).await;
+            let Some(delay) = self.retry.retry_delay(attempt, idempotent, &outcome) else {
+                return Ok(outcome?);
+            };
+            attempt += 1;
+            warn!(
+                "{} {} 失敗（{}），{:?} 後第 {} 次重試",
+                request.method(),
+                request.url(),
+                match &outcome {
+                    Ok(response) => response.status().to_string(),
+                    Err(e) => e.to_string(),
+                },
+                delay,
+                attempt
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+/// 解析 `Retry-After`（秒數或 HTTP 日期）
+fn retry_after(headers: &header::HeaderMap) -> Option<Duration> {
+    let value = headers.get(header::RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let at = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    (at.with_timezone(&chrono::Utc) - chrono::Utc::now()).to_std().ok()
+}
+
+/// 不引入 rand 的隨機數（每次建立新的 RandomState 取得隨機種子）
+fn random_u64() -> u64 {
+    std::collections::hash_map::RandomState::new()
+        .build_hasher()
+        .finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(1),
+        }
+    }
+
+    fn headers(retry_after: &str) -> header::HeaderMap {
+        let mut headers = header::HeaderMap::new();
+        headers.insert(header::RETRY_AFTER, retry_after.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn classify_status() {
+        let p = policy();
+        let none = header::HeaderMap::new();
+        // 429 對所有方法重試
+        assert!(p.status_delay(0, false, StatusCode::TOO_MANY_REQUESTS, &none).is_some());
+        assert!(p.status_delay(0, true, StatusCode::TOO_MANY_REQUESTS, &none).is_some());
+        // 502/503/504 只重試冪等方法
+        for status in [StatusCode::BAD_GATEWAY, StatusCode::SERVICE_UNAVAILABLE, StatusCode::GATEWAY_TIMEOUT] {
+            assert!(p.status_delay(0, true, status, &none).is_some(), "{status}");
+            assert!(p.status_delay(0, false, status, &none).is_none(), "{status}");
+        }
+        for status in [StatusCode::OK, StatusCode::NOT_FOUND, StatusCode::INTERNAL_SERVER_ERROR] {
+            assert!(p.status_delay(0, true, status, &none).is_none(), "{status}");
+        }
+    }
+
+    #[test]
+    fn honors_retry_after() {
+        let p = policy();
+        assert_eq!(
+            p.status_delay(0, false, StatusCode::TOO_MANY_REQUESTS, &headers("0")),
+            Some(Duration::ZERO)
+        );
+        // 超過 max_delay 時取上限
+        assert_eq!(
+            p.status_delay(0, true, StatusCode::SERVICE_UNAVAILABLE, &headers("120")),
+            Some(Duration::from_secs(1))
+        );
+        // 502 不看 Retry-After，改用退避
+        let delay = p.status_delay(0, true, StatusCode::BAD_GATEWAY, &headers("120")).unwrap();
+        assert!(delay <= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn parse_retry_after() {
+        assert_eq!(retry_after(&headers("5")), Some(Duration::from_secs(5)));
+        assert_eq!(retry_after(&headers("soon")), None);
+        assert_eq!(retry_after(&header::HeaderMap::new()), None);
+        // 過去的日期不等待
+        assert_eq!(retry_after(&headers("Wed, 21 Oct 2015 07:28:00 GMT")), None);
+        let future = (chrono::Utc::now() + chrono::Duration::seconds(60)).to_rfc2822();
+        let delay = retry_after(&headers(&future)).unwrap();
+        assert!(delay > Duration::from_secs(50) && delay <= Duration::from_secs(60));
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let p = policy();
+        for attempt in 0..40 {
+            let delay = p.backoff(attempt);
+            let cap = p.base_delay.saturating_mul(2u32.saturating_pow(attempt)).min(p.max_delay);
+            assert!(delay >= cap / 2 && delay <= cap, "attempt {attempt}: {delay:?}");
+        }
+        assert!(p.backoff(30) <= p.max_delay);
+    }
+
+    #[test]
+    fn stops_after_max_retries() {
+        let p = policy();
+        let outcome: reqwest::Result<Response> = Err(reqwest::Client::new().get("not a url").build().unwrap_err());
+        assert!(p.retry_delay(p.max_retries, true, &outcome).is_none());
+    }
+}
@@ -1,5 +1,6 @@
 //! Redmine API 類型定義
 
+use super::IssueFilter;
 use serde::{Deserialize, Serialize};
 
 // ========== Common Types ==========
@@ -48,6 +49,56 @@ pub struct Issue {
     pub journals: Option<Vec<Journal>>,
     #[serde(default)]
     pub attachments: Option<Vec<Attachment>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changesets: Option<Vec<Changeset>>,
+    /// 目前使用者依工作流程可轉換的狀態（Redmine 5.1+，含目前狀態）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_statuses: Option<Vec<IdName>>,
+}
+
+/// 關聯到 Issue 的版本庫提交
+///
+/// Redmine API 不回傳所屬版本庫；`user` 只在提交者對應到 Redmine 使用者時存在。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Changeset {
+    pub revision: String,
+    #[serde(default)]
+    pub user: Option<IdName>,
+    #[serde(default)]
+    pub comments: Option<String>,
+    #[serde(default)]
+    pub committed_on: Option<String>,
+}
+
+/// 專案的主版本庫（來自版本庫管理 Web Service）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Repository {
+    pub id: u64,
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+/// `/sys/projects.json` 的單一專案（依 Rails 設定可能包在 `project` 鍵下）
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum SysProjectEntry {
+    Wrapped { project: SysProject },
+    Plain(SysProject),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct SysProject {
+    pub id: u64,
+    #[serde(default)]
+    pub repository: Option<Repository>,
+}
+
+impl SysProjectEntry {
+    pub(crate) fn into_inner(self) -> SysProject {
+        match self {
+            Self::Wrapped { project } | Self::Plain(project) => project,
+        }
+    }
 }
 
 /// Issue Journal (歷史記錄)
@@ -59,9 +110,20 @@ pub struct Journal {
     pub notes: Option<String>,
     pub created_on: String,
     #[serde(default)]
+    pub private_notes: bool,
+    #[serde(default)]
     pub details: Vec<JournalDetail>,
 }
 
+/// Journal 備註更新參數（Redmine 5+；notes 為空字串時，無欄位變更的 journal 會被刪除）
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct JournalParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_notes: Option<bool>,
+}
+
 /// Journal 詳情
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JournalDetail {
@@ -90,6 +152,12 @@ pub struct IssueListParams {
     pub offset: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sort: Option<String>,
+    /// 已儲存查詢 ID；專案層級的查詢需同時指定 project_id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_id: Option<u64>,
+    /// 進階篩選（`f[]/op[]/v[]`），另行組成查詢字串
+    #[serde(default, skip_serializing)]
+    pub filters: IssueFilter,
 }
 
 /// Issue 更新參數
@@ -98,6 +166,10 @@ pub struct IssueUpdateParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_notes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_id: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assigned_to_id: Option<u64>,
@@ -105,6 +177,21 @@ pub struct IssueUpdateParams {
     pub done_ratio: Option<u8>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub priority_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_version_id: Option<u64>,
+}
+
+impl IssueUpdateParams {
+    /// 是否沒有任何要變更的欄位
+    pub fn is_empty(&self) -> bool {
+        self.notes.is_none()
+            && self.description.is_none()
+            && self.status_id.is_none()
+            && self.assigned_to_id.is_none()
+            && self.done_ratio.is_none()
+            && self.priority_id.is_none()
+            && self.fixed_version_id.is_none()
+    }
 }
 
 /// Issue 關聯參數
@@ -147,6 +234,8 @@ pub struct Project {
     pub identifier: String,
     #[serde(default)]
     pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled_modules: Option<Vec<IdName>>,
 }
 
 /// 專案成員
@@ -174,6 +263,10 @@ pub struct User {
     pub last_login_on: Option<String>,
     #[serde(default)]
     pub status: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admin: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_source_id: Option<u64>,
     #[serde(default)]
     pub groups: Option<Vec<IdName>>,
     #[serde(default)]
@@ -187,6 +280,61 @@ pub struct UserMembership {
     pub roles: Vec<IdName>,
 }
 
+/// 使用者狀態：啟用
+pub const USER_STATUS_ACTIVE: u8 = 1;
+/// 使用者狀態：已註冊（未啟用）
+pub const USER_STATUS_REGISTERED: u8 = 2;
+/// 使用者狀態：已鎖定
+pub const USER_STATUS_LOCKED: u8 = 3;
+
+/// 使用者建立參數（需管理員權限）
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct UserCreateParams {
+    pub login: String,
+    pub firstname: String,
+    pub lastname: String,
+    pub mail: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generate_password: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub must_change_passwd: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_source_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mail_notification: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<u8>,
+}
+
+/// 使用者更新參數（需管理員權限）
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct UserUpdateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firstname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lastname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub must_change_passwd: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_source_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mail_notification: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<u8>,
+}
+
 /// 使用者列表查詢參數
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct UserListParams {
@@ -202,6 +350,60 @@ pub struct UserListParams {
     pub offset: Option<u64>,
 }
 
+// ========== Group Types ==========
+
+/// 群組
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Group {
+    pub id: u64,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub users: Option<Vec<IdName>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memberships: Option<Vec<UserMembership>>,
+}
+
+/// 角色詳情（含權限列表）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Role {
+    pub id: u64,
+    pub name: String,
+    #[serde(default)]
+    pub assignable: Option<bool>,
+    #[serde(default)]
+    pub issues_visibility: Option<String>,
+    #[serde(default)]
+    pub time_entries_visibility: Option<String>,
+    #[serde(default)]
+    pub users_visibility: Option<String>,
+    #[serde(default)]
+    pub permissions: Vec<String>,
+}
+
+/// 自訂欄位定義（需管理員權限）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomFieldDefinition {
+    pub id: u64,
+    pub name: String,
+    pub customized_type: String,
+    pub field_format: String,
+    #[serde(default)]
+    pub is_required: Option<bool>,
+    #[serde(default)]
+    pub multiple: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub possible_values: Option<Vec<serde_json::Value>>,
+}
+
+/// 群組建立/更新參數（需管理員權限）
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct GroupParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_ids: Option<Vec<u64>>,
+}
+
 // ========== Time Entry Types ==========
 
 /// 工時記錄
@@ -280,10 +482,18 @@ pub struct Version {
 
 // ========== Wiki Types ==========
 
+/// Wiki 父頁面參考
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WikiParent {
+    pub title: String,
+}
+
 /// Wiki 頁面摘要
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WikiPageSummary {
     pub title: String,
+    #[serde(default)]
+    pub parent: Option<WikiParent>,
     pub version: u64,
     pub created_on: String,
     pub updated_on: String,
@@ -293,19 +503,67 @@ pub struct WikiPageSummary {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WikiPage {
     pub title: String,
+    #[serde(default)]
+    pub parent: Option<WikiParent>,
     pub text: String,
     pub version: u64,
     pub author: IdName,
+    #[serde(default)]
+    pub comments: Option<String>,
     pub created_on: String,
     pub updated_on: String,
 }
 
+/// Wiki 頁面版本（歷史記錄）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WikiPageVersion {
+    pub version: u64,
+    pub author: IdName,
+    #[serde(default)]
+    pub comments: Option<String>,
+    pub updated_on: String,
+}
+
+/// Wiki 頁面差異（本地產生的 unified diff）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WikiPageDiff {
+    pub title: String,
+    pub from: u64,
+    pub to: u64,
+    pub diff: String,
+}
+
 /// Wiki 頁面更新參數
+///
+/// `version` 為預期的目前版本；若伺服器上的頁面已被他人修改，Redmine 會回傳 409
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WikiPageParams {
     pub text: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comments: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_title: Option<String>,
+}
+
+/// 子頁面改掛失敗
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WikiRenameFailure {
+    pub title: String,
+    pub error: String,
+}
+
+/// Wiki 改名結果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WikiRenameResult {
+    pub wiki_page: WikiPage,
+    /// 已改掛到新頁面的子頁面
+    pub moved_children: Vec<String>,
+    /// 改掛失敗的子頁面（此時舊頁面一律保留）
+    pub failed_children: Vec<WikiRenameFailure>,
+    /// 舊頁面是否已刪除；未刪除時內容改為指向新頁面的連結，版本歷史與附件仍保留
+    pub old_page_deleted: bool,
 }
 
 // ========== File Types ==========
@@ -321,9 +579,27 @@ pub struct ProjectFile {
     #[serde(default)]
     pub description: Option<String>,
     pub content_url: String,
+    #[serde(default)]
+    pub author: Option<IdName>,
+    #[serde(default)]
+    pub version: Option<IdName>,
+    #[serde(default)]
+    pub downloads: Option<u64>,
     pub created_on: String,
 }
 
+/// 專案檔案建立參數（token 來自 `upload_file`）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectFileParams {
+    pub token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
 /// 附件
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Attachment {
@@ -339,6 +615,22 @@ pub struct Attachment {
     pub created_on: String,
 }
 
+/// 附件更新參數
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct AttachmentUpdateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// 二進位內容（縮圖、附件原始檔）
+#[derive(Debug, Clone)]
+pub struct BinaryContent {
+    pub content_type: Option<String>,
+    pub data: bytes::Bytes,
+}
+
 /// 上傳結果
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Upload {
@@ -436,6 +728,37 @@ pub struct News {
     #[serde(default)]
     pub description: Option<String>,
     pub created_on: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments: Option<Vec<NewsComment>>,
+}
+
+/// 新聞留言
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewsComment {
+    pub id: u64,
+    pub author: IdName,
+    #[serde(default)]
+    pub content: Option<String>,
+}
+
+/// 新聞列表查詢參數
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NewsListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+}
+
+/// 新聞建立/更新參數
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NewsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 /// 通用 API 回應
@@ -470,6 +793,8 @@ pub struct ProjectListResponse {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MembershipListResponse {
     pub memberships: Vec<Membership>,
+    #[serde(default)]
+    pub total_count: u64,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -552,6 +877,12 @@ pub struct WikiPageResponse {
     pub wiki_page: WikiPage,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WikiPageHistoryResponse {
+    pub title: String,
+    pub versions: Vec<WikiPageVersion>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct FileListResponse {
     pub files: Vec<ProjectFile>,
@@ -578,6 +909,8 @@ pub struct SearchResponse {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct QueryListResponse {
     pub queries: Vec<SavedQuery>,
+    #[serde(default)]
+    pub total_count: u64,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -585,12 +918,43 @@ pub struct RoleListResponse {
     pub roles: Vec<IdName>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectResponse {
+    pub project: Project,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleResponse {
+    pub role: Role,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomFieldListResponse {
+    pub custom_fields: Vec<CustomFieldDefinition>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GroupListResponse {
-    pub groups: Vec<IdName>,
+    pub groups: Vec<Group>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GroupResponse {
+    pub group: Group,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct NewsListResponse {
     pub news: Vec<News>,
+    #[serde(default)]
+    pub total_count: u64,
+    #[serde(default)]
+    pub offset: u64,
+    #[serde(default)]
+    pub limit: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NewsResponse {
+    pub news: News,
 }
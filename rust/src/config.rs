@@ -1,8 +1,14 @@
 //! 環境變數配置
 
+use crate::client::{
+    run_secret_command, ConnectionOptions, RedmineClient, RedmineClientBuilder, RetryPolicy, DEFAULT_CACHE_TTL,
+    DEFAULT_TIMEOUT,
+};
 use crate::credential::{CredentialFile, CredentialSource, LocalConfig, ResolvedCredential};
 use crate::error::{RedmineError, Result};
+use crate::markup::TextFormat;
 use std::env;
+use std::time::Duration;
 use tracing::Level;
 
 const DEFAULT_LOG_FILE: &str = "/tmp/redmine-mcp.log";
@@ -11,25 +17,61 @@ const DEFAULT_LOG_FILE: &str = "/tmp/redmine-mcp.log";
 #[derive(Debug, Clone)]
 pub struct Config {
     pub redmine_url: String,
+    /// API key；設定 `redmine_login` 時為 Basic 認證密碼
     pub redmine_token: String,
+    /// HTTP Basic 登入帳號（REDMINE_LOGIN）
+    pub redmine_login: Option<String>,
+    /// 代理的使用者（REDMINE_SWITCH_USER，需管理員認證）
+    pub switch_user: Option<String>,
+    /// 版本庫管理 Web Service 的 API 金鑰（REDMINE_SYS_API_KEY）
+    pub sys_api_key: Option<String>,
     pub log_file: String,
     pub log_level: Level,
+    /// 伺服器文字格式（REDMINE_TEXT_FORMAT，預設 textile）
+    pub text_format: TextFormat,
+    /// 中繼資料快取有效時間（REDMINE_CACHE_TTL 秒，0 停用）
+    pub cache_ttl: Duration,
+    /// 請求逾時（REDMINE_TIMEOUT 秒，預設 30）
+    pub timeout: Duration,
+    /// 暫時性錯誤重試（REDMINE_MAX_RETRIES，預設 3，0 停用）
+    pub retry: RetryPolicy,
+    /// 代理與 TLS（profile 設定，REDMINE_PROXY 等環境變數覆寫）
+    pub connection: ConnectionOptions,
 }
 
 impl Config {
     /// 從環境變數載入配置（MCP 模式用）
     pub fn from_env() -> Result<Self> {
-        let redmine_url = env::var("REDMINE_URL")
-            .map_err(|_| RedmineError::Config("缺少環境變數: REDMINE_URL".into()))?;
-        let redmine_token = env::var("REDMINE_TOKEN")
-            .map_err(|_| RedmineError::Config("缺少環境變數: REDMINE_TOKEN".into()))?;
+        if env::var("REDMINE_URL").is_err() {
+            return Err(RedmineError::Config("缺少環境變數: REDMINE_URL".into()));
+        }
+        let resolved = Self::env_credential().unwrap_or_else(|| {
+            Err(RedmineError::Config(
+                "缺少環境變數: REDMINE_TOKEN（或 REDMINE_TOKEN_COMMAND、REDMINE_LOGIN + REDMINE_PASSWORD）".into(),
+            ))
+        })?;
+        Self::from_resolved(&resolved)
+    }
 
-        Ok(Self {
-            redmine_url: redmine_url.trim_end_matches('/').to_string(),
-            redmine_token,
-            log_file: Self::default_log_file(),
-            log_level: Self::default_log_level(),
-        })
+    /// 從 REDMINE_URL 加上 REDMINE_TOKEN / REDMINE_TOKEN_COMMAND / REDMINE_LOGIN + REDMINE_PASSWORD 組出憑證
+    fn env_credential() -> Option<Result<ResolvedCredential>> {
+        let var = |key: &str| env::var(key).ok().filter(|v| !v.is_empty());
+        let url = var("REDMINE_URL")?;
+        let login = var("REDMINE_LOGIN");
+        let secret = match (&login, var("REDMINE_TOKEN_COMMAND")) {
+            (_, Some(command)) => run_secret_command(&command),
+            (Some(_), None) => var("REDMINE_PASSWORD")
+                .ok_or_else(|| RedmineError::Config("設定 REDMINE_LOGIN 時必須同時設定 REDMINE_PASSWORD".into())),
+            (None, None) => Ok(var("REDMINE_TOKEN")?),
+        };
+        Some(secret.map(|token| ResolvedCredential {
+            url,
+            token,
+            login,
+            switch_user: None,
+            source: CredentialSource::EnvVars,
+            connection: ConnectionOptions::default(),
+        }))
     }
 
     /// 分層解析配置（CLI 模式用）
@@ -44,33 +86,29 @@ impl Config {
             let resolved = ResolvedCredential {
                 url: url.to_string(),
                 token: token.to_string(),
+                login: None,
+                switch_user: None,
                 source: CredentialSource::CliFlags,
+                connection: ConnectionOptions::default(),
             };
-            return Ok((Self::from_resolved(&resolved), resolved));
+            return Ok((Self::from_resolved(&resolved)?, resolved));
         }
 
         // 2. Env vars
-        if let (Ok(url), Ok(token)) = (env::var("REDMINE_URL"), env::var("REDMINE_TOKEN")) {
-            let resolved = ResolvedCredential {
-                url, token,
-                source: CredentialSource::EnvVars,
-            };
-            return Ok((Self::from_resolved(&resolved), resolved));
+        if let Some(resolved) = Self::env_credential() {
+            let resolved = resolved?;
+            return Ok((Self::from_resolved(&resolved)?, resolved));
         }
 
-        let cred_file = CredentialFile::load();
+        let cred_file = CredentialFile::load().map_err(|e| RedmineError::Config(format!("{e:#}")))?;
 
         // 3. --profile flag
         if let Some(profile_name) = cli_profile {
             if let Some(profile) = cred_file.as_ref().and_then(|cf| cf.get_profile(profile_name)) {
-                let resolved = ResolvedCredential {
-                    url: profile.url.clone(),
-                    token: profile.token.clone(),
-                    source: CredentialSource::GlobalProfile {
-                        profile_name: profile_name.to_string(),
-                    },
-                };
-                return Ok((Self::from_resolved(&resolved), resolved));
+                let resolved = profile.resolve(profile_name, CredentialSource::GlobalProfile {
+                    profile_name: profile_name.to_string(),
+                })?;
+                return Ok((Self::from_resolved(&resolved)?, resolved));
             }
             return Err(RedmineError::Config(
                 format!("Profile '{profile_name}' 不存在，請用 `redmine login -g --profile {profile_name}` 建立"),
@@ -80,15 +118,11 @@ impl Config {
         // 4. Local .redmine
         if let Some((config_path, local_config)) = LocalConfig::find() {
             if let Some(profile) = cred_file.as_ref().and_then(|cf| cf.get_profile(&local_config.profile)) {
-                let resolved = ResolvedCredential {
-                    url: profile.url.clone(),
-                    token: profile.token.clone(),
-                    source: CredentialSource::LocalProfile {
-                        profile_name: local_config.profile,
-                        config_path,
-                    },
-                };
-                return Ok((Self::from_resolved(&resolved), resolved));
+                let resolved = profile.resolve(&local_config.profile, CredentialSource::LocalProfile {
+                    profile_name: local_config.profile.clone(),
+                    config_path,
+                })?;
+                return Ok((Self::from_resolved(&resolved)?, resolved));
             }
             return Err(RedmineError::Config(
                 format!(".redmine 指向 profile '{}' 但不存在於 credentials.toml", local_config.profile),
@@ -97,14 +131,10 @@ impl Config {
 
         // 5. Global default
         if let Some((name, profile)) = cred_file.as_ref().and_then(|cf| cf.get_default()) {
-            let resolved = ResolvedCredential {
-                url: profile.url.clone(),
-                token: profile.token.clone(),
-                source: CredentialSource::GlobalDefault {
-                    profile_name: name.to_string(),
-                },
-            };
-            return Ok((Self::from_resolved(&resolved), resolved));
+            let resolved = profile.resolve(name, CredentialSource::GlobalDefault {
+                profile_name: name.to_string(),
+            })?;
+            return Ok((Self::from_resolved(&resolved)?, resolved));
         }
 
         Err(RedmineError::Config(
@@ -112,16 +142,48 @@ impl Config {
         ))
     }
 
-    fn from_resolved(resolved: &ResolvedCredential) -> Self {
-        Self {
+    /// 由已解析的憑證建立配置（其餘設定取自環境變數）
+    pub fn from_resolved(resolved: &ResolvedCredential) -> Result<Self> {
+        Ok(Self {
             redmine_url: resolved.url.trim_end_matches('/').to_string(),
             redmine_token: resolved.token.clone(),
+            redmine_login: resolved.login.clone(),
+            switch_user: env::var("REDMINE_SWITCH_USER")
+                .ok()
+                .filter(|v| !v.is_empty())
+                .or_else(|| resolved.switch_user.clone()),
+            sys_api_key: env::var("REDMINE_SYS_API_KEY").ok().filter(|v| !v.is_empty()),
             log_file: Self::default_log_file(),
             log_level: Self::default_log_level(),
+            text_format: Self::default_text_format()?,
+            cache_ttl: Self::default_cache_ttl(),
+            timeout: Self::default_timeout(),
+            retry: Self::default_retry(),
+            connection: resolved.connection.clone().merge(ConnectionOptions::from_env()),
+        })
+    }
+
+    /// 依配置建立客戶端建構器（快取由呼叫端決定）
+    pub fn client_builder(&self) -> RedmineClientBuilder {
+        let mut builder = RedmineClient::builder(&self.redmine_url, &self.redmine_token)
+            .text_format(self.text_format)
+            .timeout(self.timeout)
+            .retry(self.retry)
+            .connection(&self.connection);
+        if let Some(login) = &self.redmine_login {
+            builder = builder.basic_auth(login, &self.redmine_token);
+        }
+        if let Some(user) = &self.switch_user {
+            builder = builder.switch_user(user);
+        }
+        if let Some(key) = &self.sys_api_key {
+            builder = builder.sys_api_key(key);
         }
+        builder
     }
 
-    fn default_log_file() -> String {
+    /// 日誌檔路徑（LOG_FILE，預設 [`DEFAULT_LOG_FILE`]）
+    pub fn default_log_file() -> String {
         env::var("LOG_FILE").unwrap_or_else(|_| DEFAULT_LOG_FILE.into())
     }
 
@@ -131,6 +193,36 @@ impl Config {
             .unwrap_or(Level::INFO)
     }
 
+    fn default_text_format() -> Result<TextFormat> {
+        env::var("REDMINE_TEXT_FORMAT")
+            .map(|s| s.parse())
+            .unwrap_or(Ok(TextFormat::default()))
+    }
+
+    fn default_cache_ttl() -> Duration {
+        env::var("REDMINE_CACHE_TTL")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_CACHE_TTL)
+    }
+
+    fn default_timeout() -> Duration {
+        env::var("REDMINE_TIMEOUT")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_TIMEOUT)
+    }
+
+    fn default_retry() -> RetryPolicy {
+        let mut retry = RetryPolicy::default();
+        if let Some(max_retries) = env::var("REDMINE_MAX_RETRIES").ok().and_then(|s| s.parse().ok()) {
+            retry.max_retries = max_retries;
+        }
+        retry
+    }
+
     fn parse_log_level(s: &str) -> Level {
         match s.to_lowercase().as_str() {
             "debug" => Level::DEBUG,
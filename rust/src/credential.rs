@@ -1,11 +1,14 @@
 //! 憑證管理 — 多 Profile 支援
 //!
-//! 全域：~/.config/redmine/credentials.toml（含 token，0o600）
+//! 全域：~/.config/redmine/credentials.toml（0o600；token 依 secret 後端明文、加密或交給 helper）
 //! 本地：.redmine（只含 profile 名稱，可 commit）
 
+use anyhow::Context;
+use crate::client::{run_secret_command, ConnectionOptions};
+use crate::secret::{self, SecretBackend};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 // ============ Global Credentials ============
 
@@ -13,6 +16,9 @@ use std::path::PathBuf;
 pub struct CredentialFile {
     #[serde(default)]
     pub default_profile: Option<String>,
+    /// `redmine login` 儲存 token 的預設後端（plaintext、encrypted、helper:<name>）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_backend: Option<SecretBackend>,
     #[serde(default)]
     pub profiles: HashMap<String, Profile>,
 }
@@ -20,7 +26,28 @@ pub struct CredentialFile {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Profile {
     pub url: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub token: String,
+    /// 以密語加密的 token（見 [`crate::secret::encrypt`]）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encrypted_token: Option<String>,
+    /// 向此 credential helper 取得 token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credential_helper: Option<String>,
+    /// 執行此命令取得 token（如 `op read op://vault/redmine/token`），優先於 `token`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_command: Option<String>,
+    /// HTTP Basic 登入帳號；設定時 `password`（或 `token_command` 輸出）為密碼
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub login: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    /// 以此登入帳號的身分呼叫（`X-Redmine-Switch-User`，需管理員 token）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub switch_user: Option<String>,
+    /// 代理與 TLS 設定
+    #[serde(flatten)]
+    pub connection: ConnectionOptions,
 }
 
 impl CredentialFile {
@@ -28,14 +55,23 @@ impl CredentialFile {
         dirs::config_dir().map(|d| d.join("redmine").join("credentials.toml"))
     }
 
-    pub fn load() -> Option<Self> {
-        let path = Self::path()?;
-        let content = std::fs::read_to_string(&path).ok()?;
-        toml::from_str(&content).ok()
+    /// 讀取 credentials.toml；檔案不存在時回傳 `None`，無法讀取或格式錯誤時回傳錯誤
+    pub fn load() -> anyhow::Result<Option<Self>> {
+        let Some(path) = Self::path() else {
+            return Ok(None);
+        };
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e).with_context(|| format!("無法讀取 {}", path.display())),
+        };
+        toml::from_str(&content)
+            .map(Some)
+            .with_context(|| format!("{} 格式錯誤", path.display()))
     }
 
-    pub fn load_or_default() -> Self {
-        Self::load().unwrap_or_default()
+    pub fn load_or_default() -> anyhow::Result<Self> {
+        Ok(Self::load()?.unwrap_or_default())
     }
 
     pub fn save(&self) -> anyhow::Result<()> {
@@ -99,11 +135,16 @@ impl LocalConfig {
 
     pub fn save_to_cwd(&self) -> anyhow::Result<PathBuf> {
         let path = std::env::current_dir()?.join(".redmine");
-        let content = toml::to_string_pretty(self)?;
-        std::fs::write(&path, &content)?;
+        self.save(&path)?;
         Ok(path)
     }
 
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let content = toml::to_string_pretty(self)?;
+        std::fs::write(path, &content)?;
+        Ok(())
+    }
+
     pub fn remove_from_cwd() -> anyhow::Result<bool> {
         let path = std::env::current_dir()?.join(".redmine");
         if path.exists() {
@@ -117,11 +158,99 @@ impl LocalConfig {
 
 // ============ Credential Resolution ============
 
+impl Profile {
+    /// 取得實際使用的 secret：token_command > credential helper > 加密 token > 明文
+    pub fn secret(&self, name: &str) -> crate::Result<String> {
+        if let Some(command) = &self.token_command {
+            return run_secret_command(command);
+        }
+        if let Some(helper) = &self.credential_helper {
+            return secret::helper_get(helper, &self.url, name);
+        }
+        if let Some(encrypted) = &self.encrypted_token {
+            return secret::decrypt(encrypted, &secret::passphrase()?);
+        }
+        Ok(match &self.login {
+            Some(_) => self.password.clone().unwrap_or_default(),
+            None => self.token.clone(),
+        })
+    }
+
+    /// 目前使用的 secret 後端；`token_command` 時為 None
+    pub fn backend(&self) -> Option<SecretBackend> {
+        if self.token_command.is_some() {
+            return None;
+        }
+        Some(match (&self.credential_helper, &self.encrypted_token) {
+            (Some(helper), _) => SecretBackend::Helper(helper.clone()),
+            (None, Some(_)) => SecretBackend::Encrypted,
+            (None, None) => SecretBackend::Plaintext,
+        })
+    }
+
+    /// 依後端儲存 secret（取代原有的 token / password / encrypted_token / credential_helper）
+    pub fn store_secret(&mut self, name: &str, value: &str, backend: &SecretBackend) -> crate::Result<()> {
+        self.token.clear();
+        self.password = None;
+        self.encrypted_token = None;
+        self.credential_helper = None;
+        match backend {
+            SecretBackend::Plaintext if self.login.is_some() => self.password = Some(value.to_string()),
+            SecretBackend::Plaintext => self.token = value.to_string(),
+            SecretBackend::Encrypted => {
+                self.encrypted_token = Some(secret::encrypt(value, &secret::passphrase()?)?);
+            }
+            SecretBackend::Helper(helper) => {
+                secret::helper_store(helper, &self.url, name, value)?;
+                self.credential_helper = Some(helper.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// 移除 helper 中的 secret（其他後端隨 profile 一起刪除）
+    pub fn erase_secret(&self, name: &str) -> crate::Result<()> {
+        match &self.credential_helper {
+            Some(helper) => secret::helper_erase(helper, &self.url, name),
+            None => Ok(()),
+        }
+    }
+
+    /// helper 的 secret 以 profile 名稱與 URL 為 key，改名、複製或改 URL 時存一份到 `to` 之下
+    pub fn copy_helper_secret(&self, name: &str, to: &Profile, to_name: &str) -> crate::Result<()> {
+        match &self.credential_helper {
+            Some(helper) => {
+                let value = secret::helper_get(helper, &self.url, name)?;
+                secret::helper_store(helper, &to.url, to_name, &value)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// 解析為連線用的憑證
+    pub fn resolve(&self, name: &str, source: CredentialSource) -> crate::Result<ResolvedCredential> {
+        let token = self.secret(name)?;
+        Ok(ResolvedCredential {
+            url: self.url.clone(),
+            token,
+            login: self.login.clone(),
+            switch_user: self.switch_user.clone(),
+            source,
+            connection: self.connection.clone(),
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ResolvedCredential {
     pub url: String,
+    /// API key；`login` 有值時為 Basic 認證密碼
     pub token: String,
+    pub login: Option<String>,
+    pub switch_user: Option<String>,
     pub source: CredentialSource,
+    /// profile 的連線設定（CLI flags / 環境變數來源時為預設值）
+    pub connection: ConnectionOptions,
 }
 
 #[derive(Debug, Clone)]
@@ -21,6 +21,10 @@ pub enum RedmineError {
     #[error("資源不存在: {resource} (id: {id})")]
     NotFound { resource: String, id: String },
 
+    /// 版本衝突 (409)，通常是資源已被他人修改
+    #[error("版本衝突: {message}")]
+    Conflict { message: String },
+
     /// 驗證錯誤 (422)
     #[error("驗證錯誤: {message}")]
     ValidationError { message: String, errors: Vec<String> },
@@ -45,6 +49,34 @@ pub enum RedmineError {
     #[error("檔案不存在: {path}")]
     FileNotFound { path: String },
 
+    /// 內容超過大小上限
+    #[error("{name} 大小 {size} bytes 超過上限 {limit} bytes")]
+    TooLarge { name: String, size: u64, limit: u64 },
+
+    /// 無法呈現的內容類型
+    #[error("不支援的內容: {0}")]
+    UnsupportedContent(String),
+
+    /// 工作流程不允許的狀態轉換
+    #[error("Issue #{issue_id} 無法轉換為狀態 '{status}'，可用狀態: {}", .allowed.join(", "))]
+    InvalidStatusTransition {
+        issue_id: u64,
+        status: String,
+        allowed: Vec<String>,
+    },
+
+    /// 名稱對應到多個候選
+    #[error("'{input}' 對應到多個 {kind}，請改用 ID 或更精確的名稱: {}", .candidates.join(", "))]
+    Ambiguous {
+        kind: String,
+        input: String,
+        candidates: Vec<String>,
+    },
+
+    /// 篩選條件不合法
+    #[error("篩選條件錯誤: {0}")]
+    InvalidFilter(String),
+
     /// 缺少必要參數
     #[error("缺少必要參數: {0}")]
     MissingParam(String),
@@ -74,6 +106,13 @@ impl RedmineError {
                 resource: "Resource".into(),
                 id: "unknown".into(),
             },
+            409 => Self::Conflict {
+                message: if body.trim().is_empty() {
+                    "資源已被他人修改，請重新取得最新版本".into()
+                } else {
+                    body
+                },
+            },
             422 => Self::ValidationError {
                 message: body.clone(),
                 errors: Self::parse_validation_errors(&body),
@@ -9,6 +9,9 @@ pub mod error;
 pub mod client;
 pub mod tools;
 pub mod log_viewer;
+pub mod markup;
+pub mod secret;
+pub mod shell;
 pub mod cli;
 
 pub use config::Config;
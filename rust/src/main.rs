@@ -7,7 +7,8 @@
 
 use clap::Parser;
 use redmine_mcp::cli;
-use redmine_mcp::{Config, RedmineClient, RedmineMcpServer, start_log_viewer, get_log_viewer_url};
+use redmine_mcp::client::MetadataCache;
+use redmine_mcp::{Config, RedmineMcpServer, start_log_viewer, get_log_viewer_url};
 use serde_json::{json, Value};
 use std::io::{self, BufRead, Write};
 use tracing::{debug, error, info};
@@ -40,7 +41,10 @@ fn run_mcp_server() -> anyhow::Result<()> {
     let config = Config::from_env()?;
     debug!("REDMINE_URL: {}", config.redmine_url);
 
-    let client = RedmineClient::new(&config.redmine_url, &config.redmine_token)?;
+    let client = config
+        .client_builder()
+        .cache(MetadataCache::in_memory(config.cache_ttl))
+        .build()?;
     let server = RedmineMcpServer::new(client);
 
     let rt = tokio::runtime::Runtime::new()?;
@@ -121,7 +125,7 @@ fn run_mcp_server() -> anyhow::Result<()> {
                 continue;
             }
             "tools/list" => {
-                let tools = redmine_mcp::tools::get_tool_definitions();
+                let tools = server.list_tools();
                 json!({
                     "jsonrpc": "2.0",
                     "id": id,
@@ -0,0 +1,592 @@
+//! Textile ⇄ Markdown 轉換
+//!
+//! Redmine 的文字格式由管理員設定（Textile 或 Markdown），API 不會告知。
+//! 伺服器格式透過 `REDMINE_TEXT_FORMAT` 指定，讓呼叫端永遠能以 Markdown 讀寫。
+//!
+//! 支援：標題、粗體/斜體/刪除線、行內程式碼、程式碼區塊、清單（含巢狀）、
+//! 表格、連結、圖片、引言。`#123` Issue 連結與 `[[Wiki]]` 連結兩種格式寫法相同，原樣保留。
+
+use crate::client::Issue;
+use crate::error::{RedmineError, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+
+/// Redmine 文字格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TextFormat {
+    #[default]
+    Textile,
+    Markdown,
+}
+
+impl std::str::FromStr for TextFormat {
+    type Err = RedmineError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "textile" => Ok(Self::Textile),
+            "markdown" | "common_mark" | "commonmark" => Ok(Self::Markdown),
+            _ => Err(RedmineError::Config(format!(
+                "未知的文字格式: {s}（可用: textile, markdown）"
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for TextFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Textile => write!(f, "textile"),
+            Self::Markdown => write!(f, "markdown"),
+        }
+    }
+}
+
+/// 將 Markdown 轉為伺服器使用的格式
+pub fn markdown_to_server(text: &str, server: TextFormat) -> String {
+    match server {
+        TextFormat::Textile => markdown_to_textile(text),
+        TextFormat::Markdown => text.to_string(),
+    }
+}
+
+/// 將伺服器格式的內容轉為 Markdown
+pub fn server_to_markdown(text: &str, server: TextFormat) -> String {
+    match server {
+        TextFormat::Textile => textile_to_markdown(text),
+        TextFormat::Markdown => text.to_string(),
+    }
+}
+
+/// 將 Issue 描述與歷史備註轉為 Markdown
+pub fn issue_to_markdown(issue: &mut Issue, server: TextFormat) {
+    if let Some(desc) = &issue.description {
+        issue.description = Some(server_to_markdown(desc, server));
+    }
+    for journal in issue.journals.iter_mut().flatten() {
+        if let Some(notes) = &journal.notes {
+            journal.notes = Some(server_to_markdown(notes, server));
+        }
+    }
+}
+
+// ========== Markdown → Textile ==========
+
+static MD_FENCE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\s*(```|~~~)\s*([\w+#.-]*)\s*$").unwrap());
+static MD_HEADING: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(#{1,6})\s+(.*?)\s*#*\s*$").unwrap());
+static MD_SETEXT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(=+|-+)\s*$").unwrap());
+static MD_LIST: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\s*)([-*+]|\d+[.)])\s+(.*)$").unwrap());
+static MD_QUOTE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^>\s?(.*)$").unwrap());
+static MD_RULE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\s*([-*_])(\s*[-*_]){2,}\s*$").unwrap());
+static MD_TABLE_SEP: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*\|?\s*:?-+:?\s*(\|\s*:?-+:?\s*)*\|?\s*$").unwrap());
+static MD_IMAGE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"!\[([^\]]*)\]\(([^)\s]+)\)").unwrap());
+static MD_LINK: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\[([^\[\]]+)\]\(([^)\s]+)\)").unwrap());
+
+/// Markdown → Textile
+pub fn markdown_to_textile(text: &str) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut out: Vec<String> = Vec::new();
+    let mut list_stack: Vec<(usize, char)> = Vec::new();
+    let mut in_quote = false;
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+
+        if !MD_LIST.is_match(line) {
+            list_stack.clear();
+        }
+        if !MD_QUOTE.is_match(line) {
+            in_quote = false;
+        }
+
+        // 程式碼區塊
+        if let Some(caps) = MD_FENCE.captures(line) {
+            let fence = &caps[1];
+            let lang = &caps[2];
+            if lang.is_empty() {
+                out.push("<pre>".into());
+            } else {
+                out.push(format!("<pre><code class=\"{lang}\">"));
+            }
+            i += 1;
+            while i < lines.len() && lines[i].trim() != fence {
+                out.push(lines[i].to_string());
+                i += 1;
+            }
+            out.push(if lang.is_empty() { "</pre>".into() } else { "</code></pre>".into() });
+            i += 1;
+            continue;
+        }
+
+        // 表格
+        if line.trim_start().starts_with('|') {
+            let start = i;
+            while i < lines.len() && lines[i].trim_start().starts_with('|') {
+                i += 1;
+            }
+            out.extend(md_table_to_textile(&lines[start..i]));
+            continue;
+        }
+
+        // 標題
+        if let Some(caps) = MD_HEADING.captures(line) {
+            out.push(format!("h{}. {}", caps[1].len(), md_inline(&caps[2])));
+            push_block_break(&mut out, lines.get(i + 1));
+            i += 1;
+            continue;
+        }
+        if !line.trim().is_empty() && !MD_LIST.is_match(line) {
+            if let Some(next) = lines.get(i + 1).and_then(|l| MD_SETEXT.captures(l)) {
+                let level = if next[1].starts_with('=') { 1 } else { 2 };
+                out.push(format!("h{level}. {}", md_inline(line.trim())));
+                push_block_break(&mut out, lines.get(i + 2));
+                i += 2;
+                continue;
+            }
+        }
+
+        if MD_RULE.is_match(line) {
+            out.push("---".into());
+            i += 1;
+            continue;
+        }
+
+        // 清單
+        if let Some(caps) = MD_LIST.captures(line) {
+            let indent = caps[1].replace('\t', "    ").len();
+            let kind = if caps[2].starts_with(|c: char| c.is_ascii_digit()) { '#' } else { '*' };
+            while list_stack.last().is_some_and(|(w, _)| *w > indent) {
+                list_stack.pop();
+            }
+            match list_stack.last_mut() {
+                Some((w, k)) if *w == indent => *k = kind,
+                _ => list_stack.push((indent, kind)),
+            }
+            let marker: String = list_stack.iter().map(|(_, k)| *k).collect();
+            out.push(format!("{marker} {}", md_inline(&caps[3])));
+            i += 1;
+            continue;
+        }
+
+        // 引言
+        if let Some(caps) = MD_QUOTE.captures(line) {
+            if in_quote {
+                out.push(md_inline(&caps[1]));
+            } else {
+                out.push(format!("bq. {}", md_inline(&caps[1])));
+                in_quote = true;
+            }
+            i += 1;
+            continue;
+        }
+
+        out.push(md_inline(line));
+        i += 1;
+    }
+
+    join_lines(out, text)
+}
+
+/// Textile 區塊（標題等）需以空行結束
+fn push_block_break(out: &mut Vec<String>, next: Option<&&str>) {
+    if next.is_some_and(|l| !l.trim().is_empty()) {
+        out.push(String::new());
+    }
+}
+
+fn md_table_to_textile(rows: &[&str]) -> Vec<String> {
+    let has_header = rows.len() > 1 && MD_TABLE_SEP.is_match(rows[1]);
+    let mut out = Vec::new();
+    for (idx, row) in rows.iter().enumerate() {
+        if has_header && idx == 1 {
+            continue;
+        }
+        let prefix = if has_header && idx == 0 { "_. " } else { " " };
+        let cells: Vec<String> = split_table_row(row)
+            .iter()
+            .map(|c| format!("{prefix}{} ", md_inline(c)))
+            .collect();
+        out.push(format!("|{}|", cells.join("|")));
+    }
+    out
+}
+
+fn md_inline(text: &str) -> String {
+    map_outside_code(text, '`', |s| {
+        let mut stash = Stash::default();
+        let s = BARE_URL.replace_all(s, |caps: &regex::Captures| stash.put(&caps[0]));
+        let s = MD_IMAGE.replace_all(&s, |caps: &regex::Captures| {
+            let src = stash.put(&caps[2]);
+            if caps[1].is_empty() {
+                format!("!{src}!")
+            } else {
+                format!("!{src}({})!", &caps[1])
+            }
+        });
+        let s = MD_LINK.replace_all(&s, |caps: &regex::Captures| {
+            format!("\"{}\":{}", &caps[1], stash.put(&caps[2]))
+        });
+        let s = convert_span(&s, "**", "\u{1}", "\u{1}");
+        let s = convert_span(&s, "__", "\u{1}", "\u{1}");
+        let s = convert_span(&s, "*", "_", "_");
+        let s = convert_span(&s, "~~", "-", "-");
+        stash.restore(&s.replace('\u{1}', "*"))
+    }, |code| format!("@{code}@"))
+}
+
+// ========== Textile → Markdown ==========
+
+static TX_PRE_OPEN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"^\s*<pre>\s*(?:<code(?:\s+class="([^"]*)")?>)?(.*)$"#).unwrap());
+static TX_PRE_CLOSE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(.*?)(?:</code>)?\s*</pre>\s*$").unwrap());
+static TX_BC: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^bc(\.\.?)\s+(.*)$").unwrap());
+static TX_HEADING: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^h([1-6])(?:\([^)]*\)|\{[^}]*\}|\[[^\]]*\]|[<>=])*\.\s+(.*)$").unwrap());
+static TX_PARAGRAPH: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^p(?:\([^)]*\)|\{[^}]*\}|[<>=])*\.\s+(.*)$").unwrap());
+static TX_QUOTE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^bq\.\s+(.*)$").unwrap());
+static TX_LIST: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^([*#]+)\s+(.*)$").unwrap());
+static TX_TABLE_ATTR: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^table(?:\([^)]*\)|\{[^}]*\})*\.\s*$").unwrap());
+static TX_CELL_ATTR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?:_|[<>=^~]|\\\d+|/\d+|\{[^}]*\}|\([^)]*\))+\.\s").unwrap());
+static TX_IMAGE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"!([^\s!(]+)(?:\(([^)]*)\))?!").unwrap());
+static TX_LINK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#""([^"\n]+)":(\S*[^\s.,;:!?)])"#).unwrap());
+
+/// Textile → Markdown
+pub fn textile_to_markdown(text: &str) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut out: Vec<String> = Vec::new();
+    let mut in_quote = false;
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        if line.trim().is_empty() {
+            in_quote = false;
+        }
+
+        // <pre> 程式碼區塊
+        if let Some(caps) = TX_PRE_OPEN.captures(line) {
+            let lang = caps.get(1).map(|m| m.as_str()).unwrap_or("");
+            out.push(format!("```{lang}"));
+            let first = caps.get(2).map(|m| m.as_str()).unwrap_or("");
+            if let Some(close) = TX_PRE_CLOSE.captures(first) {
+                if !close[1].is_empty() {
+                    out.push(close[1].to_string());
+                }
+                out.push("```".into());
+                i += 1;
+                continue;
+            }
+            if !first.is_empty() {
+                out.push(first.to_string());
+            }
+            i += 1;
+            while i < lines.len() {
+                if let Some(close) = TX_PRE_CLOSE.captures(lines[i]) {
+                    if !close[1].is_empty() {
+                        out.push(close[1].to_string());
+                    }
+                    break;
+                }
+                out.push(lines[i].to_string());
+                i += 1;
+            }
+            out.push("```".into());
+            i += 1;
+            continue;
+        }
+
+        // bc. 程式碼區塊（bc.. 延伸到下一個區塊標記）
+        if let Some(caps) = TX_BC.captures(line) {
+            let extended = &caps[1] == "..";
+            out.push("```".into());
+            out.push(caps[2].to_string());
+            i += 1;
+            while i < lines.len() {
+                let l = lines[i];
+                if extended {
+                    if TX_PARAGRAPH.is_match(l) || TX_HEADING.is_match(l) {
+                        break;
+                    }
+                } else if l.trim().is_empty() {
+                    break;
+                }
+                out.push(l.to_string());
+                i += 1;
+            }
+            while out.last().is_some_and(|l| l.trim().is_empty()) {
+                out.pop();
+            }
+            out.push("```".into());
+            continue;
+        }
+
+        // 表格
+        if line.trim_start().starts_with('|') || TX_TABLE_ATTR.is_match(line) {
+            let start = i;
+            while i < lines.len()
+                && (lines[i].trim_start().starts_with('|') || TX_TABLE_ATTR.is_match(lines[i]))
+            {
+                i += 1;
+            }
+            out.extend(textile_table_to_md(&lines[start..i]));
+            continue;
+        }
+
+        if let Some(caps) = TX_HEADING.captures(line) {
+            let level: usize = caps[1].parse().unwrap_or(1);
+            out.push(format!("{} {}", "#".repeat(level), textile_inline(&caps[2])));
+            i += 1;
+            continue;
+        }
+
+        if let Some(caps) = TX_QUOTE.captures(line) {
+            out.push(format!("> {}", textile_inline(&caps[1])));
+            in_quote = true;
+            i += 1;
+            continue;
+        }
+        if in_quote {
+            out.push(format!("> {}", textile_inline(line)));
+            i += 1;
+            continue;
+        }
+
+        if let Some(caps) = TX_LIST.captures(line) {
+            let markers = &caps[1];
+            let mut indent = String::new();
+            for m in markers[..markers.len() - 1].chars() {
+                indent.push_str(if m == '#' { "   " } else { "  " });
+            }
+            let bullet = if markers.ends_with('#') { "1." } else { "-" };
+            out.push(format!("{indent}{bullet} {}", textile_inline(&caps[2])));
+            i += 1;
+            continue;
+        }
+
+        if let Some(caps) = TX_PARAGRAPH.captures(line) {
+            out.push(textile_inline(&caps[1]));
+            i += 1;
+            continue;
+        }
+
+        out.push(textile_inline(line));
+        i += 1;
+    }
+
+    join_lines(out, text)
+}
+
+fn textile_table_to_md(rows: &[&str]) -> Vec<String> {
+    let rows: Vec<&str> = rows.iter().copied().filter(|r| !TX_TABLE_ATTR.is_match(r)).collect();
+    let mut out = Vec::new();
+    for (idx, row) in rows.iter().enumerate() {
+        let cells: Vec<String> = split_table_row(row)
+            .iter()
+            .map(|c| textile_inline(TX_CELL_ATTR.replace(c, "").trim()))
+            .collect();
+        out.push(format!("| {} |", cells.join(" | ")));
+        if idx == 0 {
+            let sep: Vec<&str> = cells.iter().map(|_| "---").collect();
+            out.push(format!("| {} |", sep.join(" | ")));
+        }
+    }
+    out
+}
+
+fn textile_inline(text: &str) -> String {
+    map_outside_code(text, '@', |s| {
+        let mut stash = Stash::default();
+        let s = BARE_URL.replace_all(s, |caps: &regex::Captures| stash.put(&caps[0]));
+        let s = TX_IMAGE.replace_all(&s, |caps: &regex::Captures| {
+            let alt = caps.get(2).map(|m| m.as_str()).unwrap_or("");
+            format!("![{alt}]({})", stash.put(&caps[1]))
+        });
+        let s = TX_LINK.replace_all(&s, |caps: &regex::Captures| {
+            format!("[{}]({})", &caps[1], stash.put(&caps[2]))
+        });
+        let s = convert_span(&s, "*", "\u{1}", "\u{1}");
+        let s = convert_span(&s, "_", "*", "*");
+        let s = convert_span(&s, "-", "~~", "~~");
+        stash.restore(&s.replace('\u{1}', "**"))
+    }, |code| format!("`{code}`"))
+}
+
+// ========== Shared Helpers ==========
+
+static BARE_URL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"\b(?:https?|ftp)://[^\s<>"()!]*[^\s<>"()!.,;:?]"#).unwrap());
+static STASH_REF: LazyLock<Regex> = LazyLock::new(|| Regex::new("\u{2}(\\d+)\u{3}").unwrap());
+
+/// 轉換強調語法前先把 URL 與連結目標換成佔位符，避免其中的 `_x_`、`*x*` 被改寫
+#[derive(Default)]
+struct Stash(Vec<String>);
+
+impl Stash {
+    fn put(&mut self, text: &str) -> String {
+        self.0.push(text.to_string());
+        format!("\u{2}{}\u{3}", self.0.len() - 1)
+    }
+
+    fn restore(&self, text: &str) -> String {
+        STASH_REF
+            .replace_all(text, |caps: &regex::Captures| {
+                let stashed = caps[1].parse::<usize>().ok().and_then(|i| self.0.get(i));
+                stashed.map(|s| self.restore(s)).unwrap_or_default()
+            })
+            .into_owned()
+    }
+}
+
+/// 將行內程式碼以外的片段交給 `f` 轉換，程式碼片段交給 `code` 重新包裝
+fn map_outside_code(
+    text: &str,
+    delim: char,
+    f: impl Fn(&str) -> String,
+    code: impl Fn(&str) -> String,
+) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+    while let Some(start) = find_span_open(rest, &delim.to_string(), out.chars().last()) {
+        let after = &rest[start + delim.len_utf8()..];
+        let Some(len) = after.find(delim) else { break };
+        out.push_str(&f(&rest[..start]));
+        out.push_str(&code(&after[..len]));
+        rest = &after[len + delim.len_utf8()..];
+    }
+    out.push_str(&f(rest));
+    out
+}
+
+/// 把成對的 `delim` 包圍文字改為 `open`/`close` 包圍
+///
+/// 開頭分隔符前須為字首或非英數字元且後面緊接非空白；結尾分隔符前須為非空白且後面為字尾或非英數字元，
+/// 避免誤判 `snake_case`、`a * b`、`well-known` 等一般文字。
+fn convert_span(text: &str, delim: &str, open: &str, close: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+    while let Some(start) = find_span_open(rest, delim, out.chars().last()) {
+        let body_start = start + delim.len();
+        match find_span_close(&rest[body_start..], delim) {
+            Some(len) => {
+                out.push_str(&rest[..start]);
+                out.push_str(open);
+                out.push_str(&rest[body_start..body_start + len]);
+                out.push_str(close);
+                rest = &rest[body_start + len + delim.len()..];
+            }
+            None => {
+                out.push_str(&rest[..body_start]);
+                rest = &rest[body_start..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn find_span_open(text: &str, delim: &str, prev: Option<char>) -> Option<usize> {
+    let delim_char = delim.chars().next()?;
+    let mut search = 0;
+    while let Some(pos) = text[search..].find(delim) {
+        let idx = search + pos;
+        let before = text[..idx].chars().last().or(prev);
+        let after = text[idx + delim.len()..].chars().next();
+        let boundary_before = before.is_none_or(|c| !c.is_alphanumeric() && c != delim_char);
+        let opens = after.is_some_and(|c| !c.is_whitespace() && c != delim_char);
+        if boundary_before && opens {
+            return Some(idx);
+        }
+        search = idx + delim.len();
+    }
+    None
+}
+
+fn find_span_close(text: &str, delim: &str) -> Option<usize> {
+    let delim_char = delim.chars().next()?;
+    let line = text.split('\n').next().unwrap_or("");
+    let mut search = 0;
+    while let Some(pos) = line[search..].find(delim) {
+        let idx = search + pos;
+        let before = line[..idx].chars().last();
+        let after = line[idx + delim.len()..].chars().next();
+        let closes = before.is_some_and(|c| !c.is_whitespace());
+        let boundary_after = after.is_none_or(|c| !c.is_alphanumeric() && c != delim_char);
+        if idx > 0 && closes && boundary_after {
+            return Some(idx);
+        }
+        search = idx + delim.len();
+    }
+    None
+}
+
+fn split_table_row(row: &str) -> Vec<String> {
+    let trimmed = row.trim();
+    let inner = trimmed.strip_prefix('|').unwrap_or(trimmed);
+    let inner = inner.strip_suffix('|').unwrap_or(inner);
+    inner.split('|').map(|c| c.trim().to_string()).collect()
+}
+
+/// 以原文的換行風格組回，並保留結尾換行
+fn join_lines(lines: Vec<String>, original: &str) -> String {
+    let newline = if original.contains("\r\n") { "\r\n" } else { "\n" };
+    let mut joined = lines.join(newline);
+    if original.ends_with('\n') {
+        joined.push_str(newline);
+    }
+    joined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// (Markdown, Textile) 雙向轉換都應得到對方
+    const ROUND_TRIP: &[(&str, &str)] = &[
+        ("# Title\n\nBody", "h1. Title\n\nBody"),
+        ("### Sub *em* **strong**", "h3. Sub _em_ *strong*"),
+        ("- one\n- two\n  - nested\n1. first", "* one\n* two\n** nested\n# first"),
+        ("[the_link_text](https://example.com/a_b_c)", "\"the_link_text\":https://example.com/a_b_c"),
+        ("see [docs](https://example.com/*x*/_y_) now", "see \"docs\":https://example.com/*x*/_y_ now"),
+        ("![](https://example.com/img_1_.png)", "!https://example.com/img_1_.png!"),
+        ("plain https://example.com/_a_/*b* url", "plain https://example.com/_a_/*b* url"),
+        ("use `snake_case *x*` here", "use @snake_case *x*@ here"),
+        ("```rust\nlet _x_ = *y;\n```", "<pre><code class=\"rust\">\nlet _x_ = *y;\n</code></pre>"),
+        ("| A | B |\n| --- | --- |\n| *1* | 2 |", "|_. A |_. B |\n| _1_ | 2 |"),
+        ("> quoted ~~old~~", "bq. quoted -old-"),
+    ];
+
+    #[test]
+    fn markdown_to_textile_cases() {
+        for (md, textile) in ROUND_TRIP {
+            assert_eq!(markdown_to_textile(md), *textile, "markdown: {md:?}");
+        }
+    }
+
+    #[test]
+    fn textile_to_markdown_cases() {
+        for (md, textile) in ROUND_TRIP {
+            assert_eq!(textile_to_markdown(textile), *md, "textile: {textile:?}");
+        }
+    }
+
+    #[test]
+    fn round_trip_is_stable() {
+        for (md, textile) in ROUND_TRIP {
+            assert_eq!(textile_to_markdown(&markdown_to_textile(md)), *md);
+            assert_eq!(markdown_to_textile(&textile_to_markdown(textile)), *textile);
+        }
+    }
+
+    #[test]
+    fn keeps_trailing_newline_and_crlf() {
+        assert_eq!(markdown_to_textile("**a**\r\n"), "*a*\r\n");
+        assert_eq!(textile_to_markdown("*a*\n"), "**a**\n");
+    }
+}
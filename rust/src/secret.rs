@@ -0,0 +1,327 @@
+//! Secret 儲存後端 — profile 的 token（或 Basic 密碼）存放方式
+//!
+//! - `plaintext`：直接寫入 credentials.toml（0o600，預設）
+//! - `encrypted`：AES-256-GCM 加密後寫入，金鑰由密語以 PBKDF2 導出；
+//!   密語取自 `REDMINE_PASSPHRASE`，未設定時在終端機上詢問
+//! - `helper:<name>`：仿 git credential helper，執行 `redmine-credential-<name>`
+//!   （含空白或 `/` 時視為完整命令），以 `get`/`store`/`erase` 與 stdin 的
+//!   `key=value` 行交換資料，secret 放在 `password=` 欄位
+
+use crate::error::{RedmineError, Result};
+use base64::Engine;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, rand_core::RngCore};
+use aes_gcm::{Aes256Gcm, Nonce};
+use sha2::Sha256;
+use std::io::{BufRead, IsTerminal, Write};
+use std::process::Stdio;
+
+const ENCRYPTED_PREFIX: &str = "v1:";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+const PBKDF2_ITERATIONS: u32 = 200_000;
+
+/// Secret 儲存後端
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum SecretBackend {
+    #[default]
+    Plaintext,
+    Encrypted,
+    Helper(String),
+}
+
+impl std::str::FromStr for SecretBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "plaintext" => Ok(Self::Plaintext),
+            "encrypted" => Ok(Self::Encrypted),
+            _ => match s.strip_prefix("helper:") {
+                Some(helper) if !helper.is_empty() => Ok(Self::Helper(helper.to_string())),
+                _ => Err(format!("不支援的 secret 後端: {s}（plaintext、encrypted 或 helper:<name>）")),
+            },
+        }
+    }
+}
+
+impl TryFrom<String> for SecretBackend {
+    type Error = String;
+
+    fn try_from(s: String) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<SecretBackend> for String {
+    fn from(backend: SecretBackend) -> Self {
+        backend.to_string()
+    }
+}
+
+impl std::fmt::Display for SecretBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Plaintext => write!(f, "plaintext"),
+            Self::Encrypted => write!(f, "encrypted"),
+            Self::Helper(helper) => write!(f, "helper:{helper}"),
+        }
+    }
+}
+
+// ============ Encrypted ============
+
+/// 以密語加密，回傳 `v1:<base64(salt | nonce | ciphertext | tag)>`
+pub fn encrypt(secret: &str, passphrase: &str) -> Result<String> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let cipher = Aes256Gcm::new(&derive_key(passphrase, &salt).into());
+
+    let sealed = cipher
+        .encrypt(&nonce, secret.as_bytes())
+        .map_err(|_| RedmineError::Config("加密失敗".into()))?;
+
+    let blob = [&salt[..], &nonce[..], &sealed[..]].concat();
+    Ok(format!(
+        "{ENCRYPTED_PREFIX}{}",
+        base64::engine::general_purpose::STANDARD.encode(blob)
+    ))
+}
+
+/// 解密 [`encrypt`] 的輸出；密語錯誤時回傳 `RedmineError::Config`
+pub fn decrypt(encrypted: &str, passphrase: &str) -> Result<String> {
+    let blob = encrypted
+        .strip_prefix(ENCRYPTED_PREFIX)
+        .and_then(|b| base64::engine::general_purpose::STANDARD.decode(b).ok())
+        .filter(|b| b.len() >= SALT_LEN + NONCE_LEN + TAG_LEN)
+        .ok_or_else(|| RedmineError::Config("encrypted_token 格式錯誤".into()))?;
+    let (salt, rest) = blob.split_at(SALT_LEN);
+    let (nonce, sealed) = rest.split_at(NONCE_LEN);
+    let cipher = Aes256Gcm::new(&derive_key(passphrase, salt).into());
+
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(nonce), sealed)
+        .map_err(|_| RedmineError::Config("無法解密 token：密語錯誤".into()))?;
+    String::from_utf8(plaintext).map_err(|_| RedmineError::Config("解密後的 token 不是 UTF-8".into()))
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> [u8; 32] {
+    pbkdf2::pbkdf2_hmac_array::<Sha256, 32>(passphrase.as_bytes(), salt, PBKDF2_ITERATIONS)
+}
+
+/// 取得加密用密語：`REDMINE_PASSPHRASE`，否則在終端機上詢問
+pub fn passphrase() -> Result<String> {
+    if let Some(passphrase) = std::env::var("REDMINE_PASSPHRASE").ok().filter(|p| !p.is_empty()) {
+        return Ok(passphrase);
+    }
+    if !std::io::stdin().is_terminal() {
+        return Err(RedmineError::Config(
+            "token 已加密，請設定 REDMINE_PASSPHRASE 或在終端機上執行".into(),
+        ));
+    }
+    prompt_hidden("Passphrase: ")
+}
+
+// ============ Credential helper ============
+
+/// 向 helper 取得 secret
+pub fn helper_get(helper: &str, url: &str, profile: &str) -> Result<String> {
+    let output = run_helper(helper, "get", &helper_request(url, profile, None))?;
+    output
+        .lines()
+        .find_map(|line| line.strip_prefix("password="))
+        .map(String::from)
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| RedmineError::Config(format!("credential helper '{helper}' 沒有回傳 password")))
+}
+
+/// 將 secret 存入 helper
+pub fn helper_store(helper: &str, url: &str, profile: &str, secret: &str) -> Result<()> {
+    run_helper(helper, "store", &helper_request(url, profile, Some(secret))).map(|_| ())
+}
+
+/// 從 helper 移除 secret
+pub fn helper_erase(helper: &str, url: &str, profile: &str) -> Result<()> {
+    run_helper(helper, "erase", &helper_request(url, profile, None)).map(|_| ())
+}
+
+fn helper_request(url: &str, profile: &str, secret: Option<&str>) -> String {
+    let parsed = url::Url::parse(url).ok();
+    let mut request = String::new();
+    if let Some(u) = &parsed {
+        request.push_str(&format!("protocol={}\n", u.scheme()));
+        if let Some(host) = u.host_str() {
+            match u.port() {
+                Some(port) => request.push_str(&format!("host={host}:{port}\n")),
+                None => request.push_str(&format!("host={host}\n")),
+            }
+        }
+        let path = u.path().trim_matches('/');
+        if !path.is_empty() {
+            request.push_str(&format!("path={path}\n"));
+        }
+    }
+    request.push_str(&format!("username={profile}\n"));
+    if let Some(secret) = secret {
+        request.push_str(&format!("password={secret}\n"));
+    }
+    request.push('\n');
+    request
+}
+
+fn run_helper(helper: &str, action: &str, input: &str) -> Result<String> {
+    let command = if helper.contains(' ') || helper.contains('/') {
+        format!("{helper} {action}")
+    } else {
+        format!("redmine-credential-{helper} {action}")
+    };
+    let mut child = crate::shell::command(&command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(|e| RedmineError::Config(format!("無法執行 credential helper '{command}': {e}")))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(input.as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(RedmineError::Config(format!(
+            "credential helper '{command}' 執行失敗 ({})",
+            output.status
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+// ============ Prompt ============
+
+/// 讀取 secret：終端機上關閉回顯詢問，否則從 stdin 讀一行
+pub fn prompt_hidden(prompt: &str) -> Result<String> {
+    let stdin = std::io::stdin();
+    let interactive = stdin.is_terminal();
+    if interactive {
+        eprint!("{prompt}");
+        std::io::stderr().flush()?;
+    }
+
+    let _echo = interactive.then(EchoGuard::disable);
+    let mut line = String::new();
+    stdin.lock().read_line(&mut line)?;
+    drop(_echo);
+    if interactive {
+        eprintln!();
+    }
+
+    let secret = line.trim().to_string();
+    if secret.is_empty() {
+        return Err(RedmineError::Config("未輸入任何內容".into()));
+    }
+    Ok(secret)
+}
+
+/// 關閉終端機回顯，drop 時還原
+struct EchoGuard {
+    #[cfg(unix)]
+    original: Option<libc::termios>,
+    #[cfg(windows)]
+    original: Option<(windows_sys::Win32::Foundation::HANDLE, u32)>,
+}
+
+impl EchoGuard {
+    #[cfg(unix)]
+    fn disable() -> Self {
+        // SAFETY: tcgetattr/tcsetattr 只讀寫傳入的 termios 結構
+        unsafe {
+            let mut term = std::mem::zeroed::<libc::termios>();
+            if libc::tcgetattr(libc::STDIN_FILENO, &mut term) != 0 {
+                return Self { original: None };
+            }
+            let original = term;
+            term.c_lflag &= !libc::ECHO;
+            libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &term);
+            Self { original: Some(original) }
+        }
+    }
+
+    #[cfg(windows)]
+    fn disable() -> Self {
+        use windows_sys::Win32::System::Console::{
+            GetConsoleMode, GetStdHandle, SetConsoleMode, ENABLE_ECHO_INPUT, STD_INPUT_HANDLE,
+        };
+        // SAFETY: GetConsoleMode/SetConsoleMode 只讀寫傳入的模式值
+        unsafe {
+            let handle = GetStdHandle(STD_INPUT_HANDLE);
+            let mut mode: u32 = 0;
+            if GetConsoleMode(handle, &mut mode) == 0 {
+                return Self { original: None };
+            }
+            SetConsoleMode(handle, mode & !ENABLE_ECHO_INPUT);
+            Self { original: Some((handle, mode)) }
+        }
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    fn disable() -> Self {
+        Self {}
+    }
+}
+
+impl Drop for EchoGuard {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        if let Some(original) = &self.original {
+            // SAFETY: 還原 disable() 取得的原始設定
+            unsafe {
+                libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, original);
+            }
+        }
+        #[cfg(windows)]
+        if let Some((handle, mode)) = self.original {
+            // SAFETY: 還原 disable() 取得的原始模式
+            unsafe {
+                windows_sys::Win32::System::Console::SetConsoleMode(handle, mode);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_round_trip() {
+        let encrypted = encrypt("s3cret-token", "passphrase").unwrap();
+        assert!(encrypted.starts_with(ENCRYPTED_PREFIX));
+        assert_eq!(decrypt(&encrypted, "passphrase").unwrap(), "s3cret-token");
+        // 密語錯誤時 GCM 驗證失敗
+        assert!(matches!(decrypt(&encrypted, "wrong"), Err(RedmineError::Config(_))));
+    }
+
+    #[test]
+    fn decrypt_rejects_malformed_input() {
+        for input in ["", "v1:", "v1:not-base64!", "v2:AAAA", "v1:AAAAAAAAAAAAAAAA"] {
+            assert!(decrypt(input, "passphrase").is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn parse_secret_backend() {
+        assert_eq!("plaintext".parse(), Ok(SecretBackend::Plaintext));
+        assert_eq!("encrypted".parse(), Ok(SecretBackend::Encrypted));
+        assert_eq!("helper:pass".parse(), Ok(SecretBackend::Helper("pass".into())));
+        assert_eq!(
+            "helper:/usr/bin/my-helper --vault".parse(),
+            Ok(SecretBackend::Helper("/usr/bin/my-helper --vault".into()))
+        );
+        for input in ["", "helper:", "keychain", "Plaintext"] {
+            assert!(input.parse::<SecretBackend>().is_err(), "{input}");
+        }
+        for backend in [SecretBackend::Plaintext, SecretBackend::Encrypted, SecretBackend::Helper("pass".into())] {
+            assert_eq!(backend.to_string().parse(), Ok(backend));
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! 跨平台 shell 命令 — Unix 以 `sh -c`、Windows 以 `cmd /C` 執行
+//!
+//! `token_command`、credential helper 與 `$EDITOR` 都是使用者寫的命令列，
+//! 可能帶參數或管線，因此交給系統 shell 解析。
+
+use std::process::Command;
+
+/// 建立以系統 shell 執行 `command` 的 [`Command`]
+pub fn command(command: &str) -> Command {
+    #[cfg(windows)]
+    {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C").arg(command);
+        cmd
+    }
+    #[cfg(not(windows))]
+    {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(command);
+        cmd
+    }
+}
+
+/// 將參數加上引號，讓 shell 視為單一字串
+pub fn quote(arg: &str) -> String {
+    if cfg!(windows) {
+        format!("\"{}\"", arg.replace('"', "\"\""))
+    } else {
+        format!("'{}'", arg.replace('\'', r"'\''"))
+    }
+}
@@ -6,9 +6,11 @@ pub use params::*;
 
 use crate::client::*;
 use crate::error::Result;
+use crate::markup;
 use crate::RedmineClient;
 use serde_json::{json, Value};
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock};
 use tracing::{error, info};
 
 /// MCP Server 工具處理結果
@@ -20,19 +22,46 @@ pub struct ToolResult {
 
 /// 工具回應內容
 #[derive(Debug)]
-pub struct ToolContent {
-    pub r#type: String,
-    pub text: String,
+pub enum ToolContent {
+    /// 文字內容
+    Text { text: String },
+    /// 圖片內容（base64 編碼）
+    Image { data: String, mime_type: String },
+}
+
+impl ToolContent {
+    /// 建立文字內容
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text { text: text.into() }
+    }
+
+    /// 建立圖片內容
+    pub fn image(bytes: &[u8], mime_type: impl Into<String>) -> Self {
+        use base64::Engine;
+        Self::Image {
+            data: base64::engine::general_purpose::STANDARD.encode(bytes),
+            mime_type: mime_type.into(),
+        }
+    }
+
+    /// 轉換為 MCP content block
+    pub fn to_json(&self) -> Value {
+        match self {
+            Self::Text { text } => json!({ "type": "text", "text": text }),
+            Self::Image { data, mime_type } => {
+                json!({ "type": "image", "data": data, "mimeType": mime_type })
+            }
+        }
+    }
 }
 
 impl ToolResult {
     /// 建立成功回應
     pub fn success(data: impl serde::Serialize) -> Self {
         Self {
-            content: vec![ToolContent {
-                r#type: "text".to_string(),
-                text: serde_json::to_string_pretty(&data).unwrap_or_default(),
-            }],
+            content: vec![ToolContent::text(
+                serde_json::to_string_pretty(&data).unwrap_or_default(),
+            )],
             is_error: false,
         }
     }
@@ -40,21 +69,14 @@ impl ToolResult {
     /// 建立錯誤回應
     pub fn error(message: impl Into<String>) -> Self {
         Self {
-            content: vec![ToolContent {
-                r#type: "text".to_string(),
-                text: format!("錯誤: {}", message.into()),
-            }],
+            content: vec![ToolContent::text(format!("錯誤: {}", message.into()))],
             is_error: true,
         }
     }
 
     /// 轉換為 JSON
     pub fn to_json(&self) -> Value {
-        let content: Vec<Value> = self
-            .content
-            .iter()
-            .map(|c| json!({ "type": c.r#type, "text": c.text }))
-            .collect();
+        let content: Vec<Value> = self.content.iter().map(ToolContent::to_json).collect();
 
         if self.is_error {
             json!({ "content": content, "isError": true })
@@ -67,19 +89,21 @@ impl ToolResult {
 /// Redmine MCP Server
 pub struct RedmineMcpServer {
     client: Arc<RedmineClient>,
+    admin_tools: bool,
 }
 
 impl RedmineMcpServer {
-    /// 建立新的 MCP Server
+    /// 建立新的 MCP Server（REDMINE_ADMIN_TOOLS=1 時啟用管理工具）
     pub fn new(client: RedmineClient) -> Self {
         Self {
             client: Arc::new(client),
+            admin_tools: admin_tools_enabled(),
         }
     }
 
     /// 取得工具列表
     pub fn list_tools(&self) -> Vec<Value> {
-        TOOL_DEFINITIONS.to_vec()
+        get_tool_definitions()
     }
 
     /// 執行工具
@@ -87,7 +111,15 @@ impl RedmineMcpServer {
         let start = std::time::Instant::now();
         info!("[請求] {} {:?}", name, args);
 
-        let result = self.execute_tool(name, args).await;
+        let result = match name {
+            "redmine_get_attachment_thumbnail" | "redmine_get_attachment_content" => {
+                self.execute_content_tool(name, args).await
+            }
+            _ => self
+                .execute_tool(name, args)
+                .await
+                .map(|data| ToolResult::success(data).content),
+        };
         let elapsed = start.elapsed();
 
         match &result {
@@ -96,58 +128,266 @@ impl RedmineMcpServer {
         }
 
         match result {
-            Ok(data) => ToolResult::success(data),
+            Ok(content) => ToolResult {
+                content,
+                is_error: false,
+            },
             Err(e) => ToolResult::error(e.to_string()),
         }
     }
 
+    /// 執行回傳非 JSON 內容（如圖片）的工具
+    async fn execute_content_tool(&self, name: &str, args: Option<Value>) -> Result<Vec<ToolContent>> {
+        let args = args.unwrap_or(json!({}));
+
+        match name {
+            "redmine_get_attachment_thumbnail" => {
+                let id = get_required_u64(&args, "id")?;
+                let size = args
+                    .get("size")
+                    .and_then(|v| v.as_u64())
+                    .map(|s| {
+                        u32::try_from(s).map_err(|_| crate::error::RedmineError::ValidationError {
+                            message: format!("size {s} 超出範圍"),
+                            errors: vec![],
+                        })
+                    })
+                    .transpose()?;
+                let thumbnail = self.client.get_attachment_thumbnail(id, size).await?;
+                // 客戶端已確認 Content-Type 為 image/*
+                let mime_type = thumbnail.content_type.unwrap_or_default();
+                Ok(vec![ToolContent::image(&thumbnail.data, mime_type)])
+            }
+            "redmine_get_attachment_content" => {
+                let id = get_required_u64(&args, "id")?;
+                let max_bytes = args
+                    .get("max_bytes")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or_else(max_attachment_bytes)
+                    .min(max_attachment_bytes());
+                let (attachment, content) = self.client.get_attachment_content(id, max_bytes).await?;
+                let mime_type = attachment
+                    .content_type
+                    .clone()
+                    .or(content.content_type)
+                    .unwrap_or_default();
+                let meta = ToolContent::text(serde_json::to_string_pretty(&attachment)?);
+
+                if is_raster_image(&mime_type) {
+                    return Ok(vec![meta, ToolContent::image(&content.data, mime_type)]);
+                }
+                let text = std::str::from_utf8(&content.data)
+                    .ok()
+                    .filter(|t| is_text_mime(&mime_type) || !t.contains('\0'));
+                match text {
+                    Some(t) => Ok(vec![meta, ToolContent::text(t)]),
+                    None => Err(crate::error::RedmineError::UnsupportedContent(format!(
+                        "{} ({}) 為二進位檔案，無法以文字或圖片呈現，請改用 redmine_download",
+                        attachment.filename,
+                        if mime_type.is_empty() { "unknown" } else { &mime_type }
+                    ))),
+                }
+            }
+            _ => Err(crate::error::RedmineError::UnknownTool(name.to_string())),
+        }
+    }
+
     /// 執行工具邏輯
     async fn execute_tool(&self, name: &str, args: Option<Value>) -> Result<Value> {
         let args = args.unwrap_or(json!({}));
 
+        if ADMIN_TOOLS.contains(&name) && !self.admin_tools {
+            return Err(crate::error::RedmineError::Config(format!(
+                "{name} 為管理工具，需設定 REDMINE_ADMIN_TOOLS=1 啟用"
+            )));
+        }
+        let args = self.resolve_named_args(name, args).await?;
+
         match name {
             // Issues
             "redmine_get_issues" => {
-                let params: IssueListParams = serde_json::from_value(args)?;
-                let result = self.client.get_issues(&params).await?;
+                let query = match args.get("query_id") {
+                    Some(Value::String(s)) => Some(s.clone()),
+                    Some(Value::Number(n)) => Some(n.to_string()),
+                    _ => None,
+                };
+                let all = fetch_all_limit(&args);
+                let mut args = args;
+                if let Some(obj) = args.as_object_mut() {
+                    obj.remove("query_id");
+                }
+                let mut params: IssueListParams = serde_json::from_value(args)?;
+                if let Some(query) = query {
+                    self.client.apply_saved_query(&mut params, &query).await?;
+                }
+                let result = match all {
+                    Some(max_items) => self.client.get_all_issues(&params, Some(max_items)).await?,
+                    None => self.client.get_issues(&params).await?,
+                };
                 Ok(serde_json::to_value(result)?)
             }
             "redmine_get_issue" => {
                 let id = get_required_u64(&args, "id")?;
-                let result = self.client.get_issue(id).await?;
+                let mut result = self.client.get_issue(id).await?;
+                if wants_markdown(&args) {
+                    markup::issue_to_markdown(&mut result.issue, self.client.text_format());
+                }
                 Ok(serde_json::to_value(result)?)
             }
             "redmine_update_issue" => {
                 let id = get_required_u64(&args, "id")?;
-                let params: IssueUpdateParams = serde_json::from_value(args)?;
+                let markdown = wants_markdown(&args);
+                let status = match args.get("status") {
+                    Some(Value::String(s)) => Some(s.clone()),
+                    Some(Value::Number(n)) => Some(n.to_string()),
+                    _ => args.get("status_id").and_then(|v| v.as_u64()).map(|n| n.to_string()),
+                };
+                let mut params: IssueUpdateParams = serde_json::from_value(args)?;
+                if let Some(status) = status {
+                    params.status_id = Some(self.client.resolve_issue_status(id, &status).await?);
+                }
+                if markdown {
+                    let format = self.client.text_format();
+                    params.notes = params.notes.map(|t| markup::markdown_to_server(&t, format));
+                    params.description = params
+                        .description
+                        .map(|t| markup::markdown_to_server(&t, format));
+                }
                 self.client.update_issue(id, &params).await?;
                 Ok(json!({ "success": true }))
             }
             "redmine_get_journals" => {
                 let issue_id = get_required_u64(&args, "issue_id")?;
-                let result = self.client.get_journals(issue_id).await?;
+                let mut result = self.client.get_journals(issue_id).await?;
+                if wants_markdown(&args) {
+                    markup::issue_to_markdown(&mut result.issue, self.client.text_format());
+                }
                 Ok(serde_json::to_value(result)?)
             }
+            "redmine_add_issue_comment" => {
+                let issue_id = get_required_u64(&args, "issue_id")?;
+                let mut notes = get_required_str(&args, "notes")?.to_string();
+                if wants_markdown(&args) {
+                    notes = markup::markdown_to_server(&notes, self.client.text_format());
+                }
+                let private = args.get("private").and_then(|v| v.as_bool()).unwrap_or(false);
+                self.client.add_issue_note(issue_id, &notes, private).await?;
+                Ok(json!({ "success": true }))
+            }
+            "redmine_update_journal" => {
+                let journal_id = get_required_u64(&args, "journal_id")?;
+                let markdown = wants_markdown(&args);
+                let mut params: JournalParams = serde_json::from_value(args)?;
+                if markdown {
+                    let format = self.client.text_format();
+                    params.notes = params.notes.map(|t| markup::markdown_to_server(&t, format));
+                }
+                self.client.update_journal(journal_id, &params).await?;
+                Ok(json!({ "success": true }))
+            }
+            "redmine_bulk_update_issues" => {
+                let dry_run = args.get("dry_run").and_then(|v| v.as_bool()).unwrap_or(true);
+                let concurrency = args
+                    .get("concurrency")
+                    .and_then(|v| v.as_u64())
+                    .map_or(DEFAULT_BULK_CONCURRENCY, |n| n as usize);
+                let max_items = args.get("max_items").and_then(|v| v.as_u64());
+
+                let mut changes: IssueUpdateParams = match args.get("changes") {
+                    Some(v) => serde_json::from_value(v.clone())?,
+                    None => return Err(crate::error::RedmineError::MissingParam("changes".into())),
+                };
+                let status = match args.get("changes").and_then(|c| c.get("status")) {
+                    Some(Value::String(s)) => Some(s.clone()),
+                    Some(Value::Number(n)) => Some(n.to_string()),
+                    _ => changes.status_id.take().map(|n| n.to_string()),
+                };
+                if changes.is_empty() && status.is_none() {
+                    return Err(crate::error::RedmineError::MissingParam("changes".into()));
+                }
+
+                let ids: Vec<u64> = args
+                    .get("ids")
+                    .and_then(|v| v.as_array())
+                    .map(|a| a.iter().filter_map(|v| v.as_u64()).collect())
+                    .unwrap_or_default();
+                let has_filters = args.get("filters").and_then(|v| v.as_array()).is_some_and(|a| !a.is_empty());
+                if args.get("query_id").is_some() && (!ids.is_empty() || has_filters) {
+                    return Err(crate::error::RedmineError::InvalidFilter(
+                        "query_id 不能與 ids 或 filters 同時使用（Redmine 會忽略其他條件，改為更新整個查詢結果）".into(),
+                    ));
+                }
+                let mut params = if ids.is_empty() {
+                    IssueListParams {
+                        project_id: args.get("project_id").and_then(|v| v.as_str()).map(String::from),
+                        filters: match args.get("filters") {
+                            Some(v) => serde_json::from_value(v.clone())?,
+                            None => IssueFilter::new(),
+                        },
+                        ..Default::default()
+                    }
+                } else {
+                    IssueListParams::for_ids(&ids)
+                };
+                match args.get("query_id") {
+                    Some(Value::String(s)) => self.client.apply_saved_query(&mut params, s).await?,
+                    Some(Value::Number(n)) => {
+                        self.client.apply_saved_query(&mut params, &n.to_string()).await?
+                    }
+                    _ if ids.is_empty() && params.filters.is_empty() => {
+                        return Err(crate::error::RedmineError::MissingParam("ids / query_id / filters".into()));
+                    }
+                    _ => {}
+                }
+
+                let issues = self.client.get_all_issues(&params, max_items).await?.issues;
+                let report = self
+                    .client
+                    .bulk_update_issues(&issues, &changes, status.as_deref(), dry_run, concurrency)
+                    .await;
+                Ok(serde_json::to_value(report)?)
+            }
+            "redmine_get_issue_commits" => {
+                let issue_id = get_required_u64(&args, "issue_id")?;
+                let changesets = self.client.get_issue_changesets(issue_id).await?;
+                Ok(json!({ "issue_id": issue_id, "changesets": changesets }))
+            }
 
             // Projects
             "redmine_get_projects" => {
-                let result = self.client.get_projects().await?;
+                let max_items = args.get("max_items").and_then(|v| v.as_u64());
+                let result = self.client.get_projects(max_items).await?;
                 Ok(serde_json::to_value(result)?)
             }
             "redmine_get_project_members" => {
                 let project_id = get_required_str(&args, "project_id")?;
-                let result = self.client.get_project_members(&project_id).await?;
+                let max_items = args.get("max_items").and_then(|v| v.as_u64());
+                let result = self.client.get_project_members(&project_id, max_items).await?;
                 Ok(serde_json::to_value(result)?)
             }
+            "redmine_get_project_repositories" => {
+                let project_id = get_required_str(&args, "project_id")?;
+                let repositories = self.client.get_project_repositories(&project_id).await?;
+                Ok(json!({ "project_id": project_id, "repositories": repositories }))
+            }
 
             // Users
             "redmine_get_current_user" => {
                 let result = self.client.get_current_user().await?;
                 Ok(serde_json::to_value(result)?)
             }
+            "redmine_my_permissions" => {
+                let project = args.get("project_id").and_then(|v| v.as_str());
+                let result = self.client.get_my_permissions(project).await?;
+                Ok(serde_json::to_value(result)?)
+            }
             "redmine_get_users" => {
+                let all = fetch_all_limit(&args);
                 let params: UserListParams = serde_json::from_value(args)?;
-                let result = self.client.get_users(&params).await?;
+                let result = match all {
+                    Some(max_items) => self.client.get_all_users(&params, Some(max_items)).await?,
+                    None => self.client.get_users(&params).await?,
+                };
                 Ok(serde_json::to_value(result)?)
             }
             "redmine_get_user" => {
@@ -156,6 +396,37 @@ impl RedmineMcpServer {
                 Ok(serde_json::to_value(result)?)
             }
 
+
+            // Users (admin)
+            "redmine_create_user" => {
+                let params: UserCreateParams = serde_json::from_value(args)?;
+                let result = self.client.create_user(&params).await?;
+                Ok(serde_json::to_value(result)?)
+            }
+            "redmine_update_user" => {
+                let id = get_required_u64(&args, "id")?;
+                let params: UserUpdateParams = serde_json::from_value(args)?;
+                self.client.update_user(id, &params).await?;
+                Ok(json!({ "success": true }))
+            }
+            "redmine_delete_user" => {
+                let id = get_required_u64(&args, "id")?;
+                self.client.delete_user(id).await?;
+                Ok(json!({ "success": true }))
+            }
+            "redmine_add_user_to_group" => {
+                let group_id = get_required_u64(&args, "group_id")?;
+                let user_id = get_required_u64(&args, "user_id")?;
+                self.client.add_user_to_group(group_id, user_id).await?;
+                Ok(json!({ "success": true }))
+            }
+            "redmine_remove_user_from_group" => {
+                let group_id = get_required_u64(&args, "group_id")?;
+                let user_id = get_required_u64(&args, "user_id")?;
+                self.client.remove_user_from_group(group_id, user_id).await?;
+                Ok(json!({ "success": true }))
+            }
+
             // Metadata
             "redmine_get_trackers" => {
                 let result = self.client.get_trackers().await?;
@@ -172,8 +443,12 @@ impl RedmineMcpServer {
 
             // Time Entries
             "redmine_get_time_entries" => {
+                let all = fetch_all_limit(&args);
                 let params: TimeEntryListParams = serde_json::from_value(args)?;
-                let result = self.client.get_time_entries(&params).await?;
+                let result = match all {
+                    Some(max_items) => self.client.get_all_time_entries(&params, Some(max_items)).await?,
+                    None => self.client.get_time_entries(&params).await?,
+                };
                 Ok(serde_json::to_value(result)?)
             }
             "redmine_create_time_entry" => {
@@ -232,18 +507,71 @@ impl RedmineMcpServer {
             "redmine_get_wiki_page" => {
                 let project_id = get_required_str(&args, "project_id")?;
                 let title = get_required_str(&args, "title")?;
-                let result = self.client.get_wiki_page(&project_id, &title).await?;
+                let mut result = match args.get("version").and_then(|v| v.as_u64()) {
+                    Some(version) => {
+                        self.client
+                            .get_wiki_page_version(&project_id, &title, version)
+                            .await?
+                    }
+                    None => self.client.get_wiki_page(&project_id, &title).await?,
+                };
+                if wants_markdown(&args) {
+                    result.wiki_page.text =
+                        markup::server_to_markdown(&result.wiki_page.text, self.client.text_format());
+                }
+                Ok(serde_json::to_value(result)?)
+            }
+            "redmine_get_wiki_history" => {
+                let project_id = get_required_str(&args, "project_id")?;
+                let title = get_required_str(&args, "title")?;
+                let limit = args.get("limit").and_then(|v| v.as_u64());
+                let result = self
+                    .client
+                    .get_wiki_page_history(&project_id, &title, limit)
+                    .await?;
+                Ok(serde_json::to_value(result)?)
+            }
+            "redmine_diff_wiki_page" => {
+                let project_id = get_required_str(&args, "project_id")?;
+                let title = get_required_str(&args, "title")?;
+                let from = args.get("from").and_then(|v| v.as_u64());
+                let to = args.get("to").and_then(|v| v.as_u64());
+                let result = self
+                    .client
+                    .diff_wiki_page(&project_id, &title, from, to)
+                    .await?;
                 Ok(serde_json::to_value(result)?)
             }
             "redmine_update_wiki_page" => {
                 let project_id = get_required_str(&args, "project_id")?;
                 let title = get_required_str(&args, "title")?;
-                let params: WikiPageParams = serde_json::from_value(args)?;
+                let markdown = wants_markdown(&args);
+                let mut params: WikiPageParams = serde_json::from_value(args)?;
+                if markdown {
+                    params.text = markup::markdown_to_server(&params.text, self.client.text_format());
+                }
                 self.client
                     .update_wiki_page(&project_id, &title, &params)
                     .await?;
                 Ok(json!({ "success": true }))
             }
+            "redmine_delete_wiki_page" => {
+                let project_id = get_required_str(&args, "project_id")?;
+                let title = get_required_str(&args, "title")?;
+                self.client.delete_wiki_page(&project_id, &title).await?;
+                Ok(json!({ "success": true }))
+            }
+            "redmine_rename_wiki_page" => {
+                let project_id = get_required_str(&args, "project_id")?;
+                let title = get_required_str(&args, "title")?;
+                let new_title = get_required_str(&args, "new_title")?;
+                let delete_old = args.get("delete_old").and_then(|v| v.as_bool()).unwrap_or(false);
+                let result = self
+                    .client
+                    .rename_wiki_page(&project_id, &title, &new_title, delete_old)
+                    .await?;
+                Ok(serde_json::to_value(result)?)
+            }
 
             // Files
             "redmine_get_files" => {
@@ -256,12 +584,42 @@ impl RedmineMcpServer {
                 let result = self.client.get_attachment(id).await?;
                 Ok(serde_json::to_value(result)?)
             }
+            "redmine_update_attachment" => {
+                let id = get_required_u64(&args, "id")?;
+                let params: AttachmentUpdateParams = serde_json::from_value(args)?;
+                self.client.update_attachment(id, &params).await?;
+                Ok(json!({ "success": true }))
+            }
+            "redmine_delete_attachment" => {
+                let id = get_required_u64(&args, "id")?;
+                self.client.delete_attachment(id).await?;
+                Ok(json!({ "success": true }))
+            }
             "redmine_upload" => {
                 let file_path = get_required_str(&args, "file_path")?;
                 let description = args.get("description").and_then(|v| v.as_str());
                 let result = self.client.upload_file(&file_path, description).await?;
                 Ok(serde_json::to_value(result)?)
             }
+            "redmine_create_project_file" => {
+                let project_id = get_required_str(&args, "project_id")?;
+                let description = args.get("description").and_then(|v| v.as_str());
+                let token = match args.get("token").and_then(|v| v.as_str()) {
+                    Some(t) => t.to_string(),
+                    None => {
+                        let file_path = get_required_str(&args, "file_path")?;
+                        self.client.upload_file(&file_path, description).await?.upload.token
+                    }
+                };
+                let params = ProjectFileParams {
+                    token,
+                    version_id: args.get("version_id").and_then(|v| v.as_u64()),
+                    filename: args.get("filename").and_then(|v| v.as_str()).map(String::from),
+                    description: description.map(String::from),
+                };
+                self.client.create_project_file(&project_id, &params).await?;
+                Ok(json!({ "success": true }))
+            }
             "redmine_download" => {
                 let attachment_id = get_required_u64(&args, "attachment_id")?;
                 let save_path = get_required_str(&args, "save_path")?;
@@ -278,8 +636,12 @@ impl RedmineMcpServer {
             // Search
             "redmine_search" => {
                 let q = get_required_str(&args, "q")?;
+                let all = fetch_all_limit(&args);
                 let params: SearchParams = serde_json::from_value(args)?;
-                let result = self.client.search(&q, &params).await?;
+                let result = match all {
+                    Some(max_items) => self.client.search_all(&q, &params, Some(max_items)).await?,
+                    None => self.client.search(&q, &params).await?,
+                };
                 Ok(serde_json::to_value(result)?)
             }
 
@@ -296,11 +658,64 @@ impl RedmineMcpServer {
                 let result = self.client.get_groups().await?;
                 Ok(serde_json::to_value(result)?)
             }
+            "redmine_get_group" => {
+                let group = match args.get("id") {
+                    Some(Value::Number(n)) => n.as_u64(),
+                    Some(Value::String(s)) => Some(self.client.resolve_group(s).await?),
+                    _ => None,
+                }
+                .ok_or_else(|| crate::error::RedmineError::MissingParam("id".into()))?;
+                let result = self.client.get_group(group).await?;
+                Ok(serde_json::to_value(result)?)
+            }
+            "redmine_create_group" => {
+                get_required_str(&args, "name")?;
+                let params: GroupParams = serde_json::from_value(args)?;
+                let result = self.client.create_group(&params).await?;
+                Ok(serde_json::to_value(result)?)
+            }
+            "redmine_update_group" => {
+                let id = get_required_u64(&args, "id")?;
+                let params: GroupParams = serde_json::from_value(args)?;
+                self.client.update_group(id, &params).await?;
+                Ok(json!({ "success": true }))
+            }
+            "redmine_delete_group" => {
+                let id = get_required_u64(&args, "id")?;
+                self.client.delete_group(id).await?;
+                Ok(json!({ "success": true }))
+            }
+
+            // News
             "redmine_get_news" => {
                 let project_id = args.get("project_id").and_then(|v| v.as_str());
-                let result = self.client.get_news(project_id).await?;
+                let params: NewsListParams = serde_json::from_value(args.clone())?;
+                let result = self.client.get_news(project_id, &params).await?;
                 Ok(serde_json::to_value(result)?)
             }
+            "redmine_get_news_item" => {
+                let id = get_required_u64(&args, "id")?;
+                let result = self.client.get_news_item(id).await?;
+                Ok(serde_json::to_value(result)?)
+            }
+            "redmine_create_news" => {
+                let project_id = get_required_str(&args, "project_id")?;
+                get_required_str(&args, "title")?;
+                let params: NewsParams = serde_json::from_value(args)?;
+                self.client.create_news(&project_id, &params).await?;
+                Ok(json!({ "success": true }))
+            }
+            "redmine_update_news" => {
+                let id = get_required_u64(&args, "id")?;
+                let params: NewsParams = serde_json::from_value(args)?;
+                self.client.update_news(id, &params).await?;
+                Ok(json!({ "success": true }))
+            }
+            "redmine_delete_news" => {
+                let id = get_required_u64(&args, "id")?;
+                self.client.delete_news(id).await?;
+                Ok(json!({ "success": true }))
+            }
 
             // Generic
             "redmine_request" => {
@@ -351,3 +766,125 @@ fn get_required_str(args: &Value, key: &str) -> Result<String> {
         .map(|s| s.to_string())
         .ok_or_else(|| crate::error::RedmineError::MissingParam(key.to_string()))
 }
+
+/// 各工具 schema 的 `properties`，只建立一次供名稱解析查詢
+static TOOL_PROPERTIES: LazyLock<HashMap<String, Value>> = LazyLock::new(|| {
+    get_tool_definitions()
+        .into_iter()
+        .filter_map(|d| {
+            let name = d["name"].as_str()?.to_string();
+            Some((name, d["inputSchema"]["properties"].clone()))
+        })
+        .collect()
+});
+
+/// 可用名稱代替 ID 的參數
+const NAMED_ID_KEYS: &[&str] = &[
+    "status_id",
+    "tracker_id",
+    "priority_id",
+    "activity_id",
+    "assigned_to_id",
+    "author_id",
+    "user_id",
+    "group_id",
+];
+
+impl RedmineMcpServer {
+    /// 把參數中的名稱解析為 ID；依工具 schema 決定寫回字串或數字
+    async fn resolve_named_args(&self, tool: &str, mut args: Value) -> Result<Value> {
+        let Some(schema) = TOOL_PROPERTIES.get(tool) else {
+            return Ok(args);
+        };
+        // 先解析專案，使用者名稱才能從專案成員中比對
+        if let Some(Value::String(project)) = args.get("project_id") {
+            args["project_id"] = Value::String(self.client.resolve_project(project).await?);
+        }
+        let project = args.get("project_id").and_then(|v| v.as_str()).map(String::from);
+        self.resolve_named_object(&mut args, schema, project.as_deref()).await?;
+        if let Some(changes) = args.get_mut("changes") {
+            self.resolve_named_object(changes, &schema["changes"]["properties"], project.as_deref())
+                .await?;
+        }
+        Ok(args)
+    }
+
+    async fn resolve_named_object(
+        &self,
+        obj: &mut Value,
+        schema: &Value,
+        project: Option<&str>,
+    ) -> Result<()> {
+        for key in NAMED_ID_KEYS {
+            let Some(Value::String(input)) = obj.get(*key) else {
+                continue;
+            };
+            let input = input.clone();
+
+            // 字串型欄位是列表篩選，保留 open、me、! 等伺服器語法
+            if schema[*key]["type"] == "string" {
+                let resolved = match *key {
+                    "status_id" => self.client.resolve_status_filter(&input).await?,
+                    _ => self.client.resolve_user_filter(&input, project).await?,
+                };
+                obj[*key] = Value::String(resolved);
+                continue;
+            }
+
+            let id = match *key {
+                "status_id" => self.client.resolve_status(&input).await?,
+                "tracker_id" => self.client.resolve_tracker(&input).await?,
+                "priority_id" => self.client.resolve_priority(&input).await?,
+                "activity_id" => self.client.resolve_activity(&input).await?,
+                "group_id" => self.client.resolve_group(&input).await?,
+                _ => self.client.resolve_user(&input, project).await?,
+            };
+            obj[*key] = json!(id);
+        }
+        Ok(())
+    }
+}
+
+/// `fetch_all: true` 時回傳自動分頁的筆數上限（`max_items`，預設 1000）
+fn fetch_all_limit(args: &Value) -> Option<u64> {
+    args.get("fetch_all")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+        .then(|| clamp_max_items(args.get("max_items").and_then(|v| v.as_u64())))
+}
+
+/// 是否以 Markdown 讀寫內容（自動轉換為伺服器格式）
+fn wants_markdown(args: &Value) -> bool {
+    args.get("markdown").and_then(|v| v.as_bool()).unwrap_or(false)
+}
+
+/// 附件內容大小上限（REDMINE_MAX_ATTACHMENT_BYTES，預設 5 MiB）
+fn max_attachment_bytes() -> u64 {
+    std::env::var("REDMINE_MAX_ATTACHMENT_BYTES")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(5 * 1024 * 1024)
+}
+
+/// MCP image content 支援的點陣圖格式
+fn is_raster_image(mime_type: &str) -> bool {
+    matches!(
+        mime_type,
+        "image/png" | "image/jpeg" | "image/jpg" | "image/gif" | "image/webp"
+    )
+}
+
+fn is_text_mime(mime_type: &str) -> bool {
+    mime_type.starts_with("text/")
+        || mime_type.ends_with("+xml")
+        || mime_type.ends_with("+json")
+        || matches!(
+            mime_type,
+            "application/json"
+                | "application/xml"
+                | "application/javascript"
+                | "application/x-yaml"
+                | "application/yaml"
+                | "application/x-sh"
+        )
+}
@@ -5,47 +5,214 @@ use serde_json::{json, Value};
 /// 工具定義常量
 pub static TOOL_DEFINITIONS: &[Value] = &[];
 
+/// 管理工具（需設定 REDMINE_ADMIN_TOOLS=1 才會列出與執行）
+pub const ADMIN_TOOLS: &[&str] = &[
+    "redmine_create_user",
+    "redmine_update_user",
+    "redmine_delete_user",
+    "redmine_add_user_to_group",
+    "redmine_remove_user_from_group",
+    "redmine_create_group",
+    "redmine_update_group",
+    "redmine_delete_group",
+];
+
+/// 是否啟用管理工具
+pub fn admin_tools_enabled() -> bool {
+    std::env::var("REDMINE_ADMIN_TOOLS")
+        .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+}
+
 /// 建立工具定義
 pub fn get_tool_definitions() -> Vec<Value> {
+    let mut tools = get_base_tool_definitions();
+    if admin_tools_enabled() {
+        tools.extend(get_admin_tool_definitions());
+    }
+    tools
+}
+
+/// 管理工具定義
+fn get_admin_tool_definitions() -> Vec<Value> {
+    vec![
+        json!({
+            "name": "redmine_create_user",
+            "description": "[管理] 建立使用者",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "login": { "type": "string" },
+                    "firstname": { "type": "string" },
+                    "lastname": { "type": "string" },
+                    "mail": { "type": "string" },
+                    "password": { "type": "string" },
+                    "generate_password": { "type": "boolean" },
+                    "must_change_passwd": { "type": "boolean" },
+                    "auth_source_id": { "type": "number" },
+                    "mail_notification": { "type": "string" },
+                    "admin": { "type": "boolean" },
+                    "status": { "type": "number", "description": "1=啟用, 2=已註冊, 3=鎖定" }
+                },
+                "required": ["login", "firstname", "lastname", "mail"]
+            }
+        }),
+        json!({
+            "name": "redmine_update_user",
+            "description": "[管理] 更新使用者（status: 1=啟用/解鎖, 3=鎖定）",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": "number" },
+                    "login": { "type": "string" },
+                    "firstname": { "type": "string" },
+                    "lastname": { "type": "string" },
+                    "mail": { "type": "string" },
+                    "password": { "type": "string" },
+                    "must_change_passwd": { "type": "boolean" },
+                    "auth_source_id": { "type": "number" },
+                    "mail_notification": { "type": "string" },
+                    "admin": { "type": "boolean" },
+                    "status": { "type": "number", "description": "1=啟用, 2=已註冊, 3=鎖定" }
+                },
+                "required": ["id"]
+            }
+        }),
+        json!({
+            "name": "redmine_delete_user",
+            "description": "[管理] 刪除使用者",
+            "inputSchema": {
+                "type": "object",
+                "properties": { "id": { "type": "number" } },
+                "required": ["id"]
+            }
+        }),
+        json!({
+            "name": "redmine_add_user_to_group",
+            "description": "[管理] 將使用者加入群組",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "group_id": { "type": ["number", "string"], "description": "群組 ID 或名稱" },
+                    "user_id": { "type": ["number", "string"], "description": "使用者 ID、登入帳號、姓名或 me" }
+                },
+                "required": ["group_id", "user_id"]
+            }
+        }),
+        json!({
+            "name": "redmine_remove_user_from_group",
+            "description": "[管理] 將使用者移出群組",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "group_id": { "type": ["number", "string"], "description": "群組 ID 或名稱" },
+                    "user_id": { "type": ["number", "string"], "description": "使用者 ID、登入帳號、姓名或 me" }
+                },
+                "required": ["group_id", "user_id"]
+            }
+        }),
+        json!({
+            "name": "redmine_create_group",
+            "description": "[管理] 建立群組",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "name": { "type": "string" },
+                    "user_ids": { "type": "array", "items": { "type": "number" } }
+                },
+                "required": ["name"]
+            }
+        }),
+        json!({
+            "name": "redmine_update_group",
+            "description": "[管理] 更新群組（user_ids 會取代全部成員）",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": "number" },
+                    "name": { "type": "string" },
+                    "user_ids": { "type": "array", "items": { "type": "number" } }
+                },
+                "required": ["id"]
+            }
+        }),
+        json!({
+            "name": "redmine_delete_group",
+            "description": "[管理] 刪除群組",
+            "inputSchema": {
+                "type": "object",
+                "properties": { "id": { "type": "number" } },
+                "required": ["id"]
+            }
+        }),
+    ]
+}
+
+/// 一般工具定義
+fn get_base_tool_definitions() -> Vec<Value> {
     vec![
         // Issues
         json!({
             "name": "redmine_get_issues",
-            "description": "Issues 列表",
+            "description": "Issues 列表（可執行已儲存查詢）",
             "inputSchema": {
                 "type": "object",
                 "properties": {
-                    "project_id": { "type": "string" },
-                    "tracker_id": { "type": "number" },
-                    "status_id": { "type": "string" },
-                    "assigned_to_id": { "type": "string" },
+                    "project_id": { "type": "string", "description": "專案 ID、識別碼或名稱" },
+                    "query_id": { "type": ["number", "string"], "description": "已儲存查詢的 ID 或名稱（見 redmine_get_queries）；不能與 status_id、assigned_to_id、tracker_id、filters 並用" },
+                    "tracker_id": { "type": ["number", "string"], "description": "Tracker ID 或名稱" },
+                    "status_id": { "type": "string", "description": "open、closed、*、狀態 ID 或名稱（! 開頭表示排除）" },
+                    "assigned_to_id": { "type": "string", "description": "使用者 ID、登入帳號、姓名或 me" },
                     "limit": { "type": "number" },
                     "offset": { "type": "number" },
-                    "sort": { "type": "string" }
+                    "fetch_all": { "type": "boolean", "description": "自動分頁取得全部（忽略 limit/offset）" },
+                    "max_items": { "type": "number", "description": "fetch_all 的筆數上限（預設 1000，最多 10000）" },
+                    "sort": { "type": "string" },
+                    "filters": {
+                        "type": "array",
+                        "description": "進階篩選（Redmine f[]/op[]/v[]）。日期可用 ><、>t-、<t-、t、w、m 等；文字用 ~；自訂欄位為 cf_N；每個欄位只能有一個條件，範圍請用 ><",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "field": { "type": "string", "description": "例如 created_on、updated_on、due_date、author_id、category_id、fixed_version_id、parent_id、subproject_id、subject、cf_12" },
+                                "op": { "type": "string", "enum": ["=", "!", "o", "c", "*", "!*", ">=", "<=", "><", ">t-", "<t-", "><t-", "t-", "<t+", ">t+", "t+", "t", "ld", "w", "lw", "l2w", "m", "lm", "y", "~", "!~", "^", "$"] },
+                                "values": { "type": "array", "items": { "type": "string" } }
+                            },
+                            "required": ["field", "op"]
+                        }
+                    }
                 }
             }
         }),
         json!({
             "name": "redmine_get_issue",
-            "description": "Issue 詳情",
+            "description": "Issue 詳情（含 allowed_statuses：目前可轉換的狀態）",
             "inputSchema": {
                 "type": "object",
-                "properties": { "id": { "type": "number" } },
+                "properties": {
+                    "id": { "type": "number" },
+                    "markdown": { "type": "boolean", "description": "以 Markdown 回傳內容（依 REDMINE_TEXT_FORMAT 自動轉換）" }
+                },
                 "required": ["id"]
             }
         }),
         json!({
             "name": "redmine_update_issue",
-            "description": "更新 Issue",
+            "description": "更新 Issue；狀態轉換不合法時回傳可用狀態",
             "inputSchema": {
                 "type": "object",
                 "properties": {
                     "id": { "type": "number" },
                     "notes": { "type": "string" },
-                    "status_id": { "type": "number" },
-                    "assigned_to_id": { "type": "number" },
+                    "private_notes": { "type": "boolean", "description": "備註設為私人" },
+                    "description": { "type": "string" },
+                    "status": { "type": ["string", "number"], "description": "目標狀態名稱或 ID，送出前依工作流程驗證（見 get_issue 的 allowed_statuses）" },
+                    "status_id": { "type": ["number", "string"], "description": "狀態 ID 或名稱" },
+                    "assigned_to_id": { "type": ["number", "string"], "description": "使用者 ID、登入帳號、姓名或 me" },
                     "done_ratio": { "type": "number" },
-                    "priority_id": { "type": "number" }
+                    "priority_id": { "type": ["number", "string"], "description": "優先權 ID 或名稱" },
+                    "fixed_version_id": { "type": "number", "description": "目標版本 ID" },
+                    "markdown": { "type": "boolean", "description": "內容為 Markdown，自動轉換為伺服器格式" }
                 },
                 "required": ["id"]
             }
@@ -55,7 +222,91 @@ pub fn get_tool_definitions() -> Vec<Value> {
             "description": "Issue 歷史",
             "inputSchema": {
                 "type": "object",
-                "properties": { "issue_id": { "type": "number" } },
+                "properties": {
+                    "issue_id": { "type": "number" },
+                    "markdown": { "type": "boolean", "description": "以 Markdown 回傳內容（依 REDMINE_TEXT_FORMAT 自動轉換）" }
+                },
+                "required": ["issue_id"]
+            }
+        }),
+        json!({
+            "name": "redmine_add_issue_comment",
+            "description": "新增 Issue 備註（不變更其他欄位）",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "issue_id": { "type": "number" },
+                    "notes": { "type": "string" },
+                    "private": { "type": "boolean", "description": "設為私人備註" },
+                    "markdown": { "type": "boolean", "description": "內容為 Markdown，自動轉換為伺服器格式" }
+                },
+                "required": ["issue_id", "notes"]
+            }
+        }),
+        json!({
+            "name": "redmine_update_journal",
+            "description": "修改既有備註或私人標記（Redmine 5+）；notes 設為空字串可刪除備註",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "journal_id": { "type": "number", "description": "Journal ID（見 redmine_get_journals）" },
+                    "notes": { "type": "string" },
+                    "private_notes": { "type": "boolean" },
+                    "markdown": { "type": "boolean", "description": "內容為 Markdown，自動轉換為伺服器格式" }
+                },
+                "required": ["journal_id"]
+            }
+        }),
+        json!({
+            "name": "redmine_bulk_update_issues",
+            "description": "批次更新 Issue。預設 dry_run=true 只預覽並驗證（含狀態轉換），確認後以 dry_run=false 送出；回傳逐筆成功/失敗報告",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "ids": { "type": "array", "items": { "type": "number" }, "description": "Issue ID 清單" },
+                    "query_id": { "type": ["number", "string"], "description": "以已儲存查詢選取（ID 或名稱）；不能與 ids、filters 並用" },
+                    "filters": {
+                        "type": "array",
+                        "description": "以進階篩選選取，格式同 redmine_get_issues",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "field": { "type": "string" },
+                                "op": { "type": "string" },
+                                "values": { "type": "array", "items": { "type": "string" } }
+                            },
+                            "required": ["field", "op"]
+                        }
+                    },
+                    "project_id": { "type": "string", "description": "專案 ID、識別碼或名稱" },
+                    "changes": {
+                        "type": "object",
+                        "description": "要套用的更新",
+                        "properties": {
+                            "status": { "type": ["string", "number"], "description": "目標狀態名稱或 ID" },
+                            "assigned_to_id": { "type": ["number", "string"], "description": "使用者 ID、登入帳號、姓名或 me" },
+                            "fixed_version_id": { "type": "number" },
+                            "priority_id": { "type": ["number", "string"], "description": "優先權 ID 或名稱" },
+                            "done_ratio": { "type": "number" },
+                            "notes": { "type": "string" },
+                            "private_notes": { "type": "boolean" }
+                        }
+                    },
+                    "dry_run": { "type": "boolean", "description": "只預覽不送出（預設 true）" },
+                    "concurrency": { "type": "number", "description": "並行數（預設 4，最多 10）" },
+                    "max_items": { "type": "number", "description": "選取筆數上限（預設 1000）" }
+                },
+                "required": ["changes"]
+            }
+        }),
+        json!({
+            "name": "redmine_get_issue_commits",
+            "description": "Issue 關聯的版本庫提交（revision、作者、訊息），用於從 Issue 追到程式碼",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "issue_id": { "type": "number" }
+                },
                 "required": ["issue_id"]
             }
         }),
@@ -63,15 +314,34 @@ pub fn get_tool_definitions() -> Vec<Value> {
         // Projects & Users
         json!({
             "name": "redmine_get_projects",
-            "description": "專案列表",
-            "inputSchema": { "type": "object", "properties": {} }
+            "description": "專案列表（自動分頁）",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "max_items": { "type": "number", "description": "筆數上限（預設與上限 10000）" }
+                }
+            }
         }),
         json!({
             "name": "redmine_get_project_members",
-            "description": "專案成員",
+            "description": "專案成員（自動分頁）",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "project_id": { "type": "string", "description": "專案 ID、識別碼或名稱" },
+                    "max_items": { "type": "number", "description": "筆數上限（預設與上限 10000）" }
+                },
+                "required": ["project_id"]
+            }
+        }),
+        json!({
+            "name": "redmine_get_project_repositories",
+            "description": "專案的主版本庫（id、url），需伺服器啟用版本庫管理 Web Service 並設定 REDMINE_SYS_API_KEY",
             "inputSchema": {
                 "type": "object",
-                "properties": { "project_id": { "type": "string" } },
+                "properties": {
+                    "project_id": { "type": "string", "description": "專案 ID、識別碼或名稱" }
+                },
                 "required": ["project_id"]
             }
         }),
@@ -80,6 +350,16 @@ pub fn get_tool_definitions() -> Vec<Value> {
             "description": "當前使用者",
             "inputSchema": { "type": "object", "properties": {} }
         }),
+        json!({
+            "name": "redmine_my_permissions",
+            "description": "目前 token 在各專案實際可用的權限（角色權限 × 啟用模組），呼叫前可先確認避免 Forbidden",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "project_id": { "type": "string", "description": "只查詢此專案（ID 或識別碼）" }
+                }
+            }
+        }),
         json!({
             "name": "redmine_get_users",
             "description": "使用者列表",
@@ -88,9 +368,11 @@ pub fn get_tool_definitions() -> Vec<Value> {
                 "properties": {
                     "status": { "type": "number" },
                     "name": { "type": "string" },
-                    "group_id": { "type": "number" },
+                    "group_id": { "type": ["number", "string"], "description": "群組 ID 或名稱" },
                     "limit": { "type": "number" },
-                    "offset": { "type": "number" }
+                    "offset": { "type": "number" },
+                    "fetch_all": { "type": "boolean", "description": "自動分頁取得全部（忽略 limit/offset）" },
+                    "max_items": { "type": "number", "description": "fetch_all 的筆數上限（預設 1000，最多 10000）" }
                 }
             }
         }),
@@ -128,12 +410,14 @@ pub fn get_tool_definitions() -> Vec<Value> {
             "inputSchema": {
                 "type": "object",
                 "properties": {
-                    "project_id": { "type": "string" },
-                    "user_id": { "type": "string" },
+                    "project_id": { "type": "string", "description": "專案 ID、識別碼或名稱" },
+                    "user_id": { "type": "string", "description": "使用者 ID、登入帳號、姓名或 me" },
                     "from": { "type": "string" },
                     "to": { "type": "string" },
                     "limit": { "type": "number" },
-                    "offset": { "type": "number" }
+                    "offset": { "type": "number" },
+                    "fetch_all": { "type": "boolean", "description": "自動分頁取得全部（忽略 limit/offset）" },
+                    "max_items": { "type": "number", "description": "fetch_all 的筆數上限（預設 1000，最多 10000）" }
                 }
             }
         }),
@@ -144,9 +428,9 @@ pub fn get_tool_definitions() -> Vec<Value> {
                 "type": "object",
                 "properties": {
                     "issue_id": { "type": "number" },
-                    "project_id": { "type": "string" },
+                    "project_id": { "type": "string", "description": "專案 ID、識別碼或名稱" },
                     "hours": { "type": "number" },
-                    "activity_id": { "type": "number" },
+                    "activity_id": { "type": ["number", "string"], "description": "活動 ID 或名稱" },
                     "comments": { "type": "string" },
                     "spent_on": { "type": "string" }
                 },
@@ -165,7 +449,7 @@ pub fn get_tool_definitions() -> Vec<Value> {
             "description": "版本列表",
             "inputSchema": {
                 "type": "object",
-                "properties": { "project_id": { "type": "string" } },
+                "properties": { "project_id": { "type": "string", "description": "專案 ID、識別碼或名稱" } },
                 "required": ["project_id"]
             }
         }),
@@ -219,7 +503,7 @@ pub fn get_tool_definitions() -> Vec<Value> {
             "description": "Issue 分類",
             "inputSchema": {
                 "type": "object",
-                "properties": { "project_id": { "type": "string" } },
+                "properties": { "project_id": { "type": "string", "description": "專案 ID、識別碼或名稱" } },
                 "required": ["project_id"]
             }
         }),
@@ -230,36 +514,94 @@ pub fn get_tool_definitions() -> Vec<Value> {
             "description": "Wiki 列表",
             "inputSchema": {
                 "type": "object",
-                "properties": { "project_id": { "type": "string" } },
+                "properties": { "project_id": { "type": "string", "description": "專案 ID、識別碼或名稱" } },
                 "required": ["project_id"]
             }
         }),
         json!({
             "name": "redmine_get_wiki_page",
-            "description": "Wiki 內容",
+            "description": "Wiki 內容（可指定 version 取得舊版）",
             "inputSchema": {
                 "type": "object",
                 "properties": {
-                    "project_id": { "type": "string" },
-                    "title": { "type": "string" }
+                    "project_id": { "type": "string", "description": "專案 ID、識別碼或名稱" },
+                    "title": { "type": "string" },
+                    "version": { "type": "number", "description": "版本號（預設最新）" },
+                    "markdown": { "type": "boolean", "description": "以 Markdown 回傳內容（依 REDMINE_TEXT_FORMAT 自動轉換）" }
+                },
+                "required": ["project_id", "title"]
+            }
+        }),
+        json!({
+            "name": "redmine_get_wiki_history",
+            "description": "Wiki 頁面歷史（版本、作者、備註）",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "project_id": { "type": "string", "description": "專案 ID、識別碼或名稱" },
+                    "title": { "type": "string" },
+                    "limit": { "type": "number", "description": "最多回傳幾個版本（從最新往回）" }
+                },
+                "required": ["project_id", "title"]
+            }
+        }),
+        json!({
+            "name": "redmine_diff_wiki_page",
+            "description": "比較 Wiki 頁面兩個版本（unified diff）。預設比較最新版與前一版",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "project_id": { "type": "string", "description": "專案 ID、識別碼或名稱" },
+                    "title": { "type": "string" },
+                    "from": { "type": "number", "description": "舊版本號（預設 to - 1）" },
+                    "to": { "type": "number", "description": "新版本號（預設最新）" }
                 },
                 "required": ["project_id", "title"]
             }
         }),
         json!({
             "name": "redmine_update_wiki_page",
-            "description": "更新 Wiki",
+            "description": "更新 Wiki（頁面不存在時建立）。帶 version 可避免覆蓋他人修改，版本不符時回傳衝突錯誤",
             "inputSchema": {
                 "type": "object",
                 "properties": {
-                    "project_id": { "type": "string" },
+                    "project_id": { "type": "string", "description": "專案 ID、識別碼或名稱" },
                     "title": { "type": "string" },
                     "text": { "type": "string" },
-                    "comments": { "type": "string" }
+                    "comments": { "type": "string" },
+                    "version": { "type": "number", "description": "預期的目前版本（取自 redmine_get_wiki_page）" },
+                    "parent_title": { "type": "string", "description": "父頁面標題" },
+                    "markdown": { "type": "boolean", "description": "內容為 Markdown，自動轉換為伺服器格式" }
                 },
                 "required": ["project_id", "title", "text"]
             }
         }),
+        json!({
+            "name": "redmine_delete_wiki_page",
+            "description": "刪除 Wiki 頁面（子頁面移至頂層）",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "project_id": { "type": "string", "description": "專案 ID、識別碼或名稱" },
+                    "title": { "type": "string" }
+                },
+                "required": ["project_id", "title"]
+            }
+        }),
+        json!({
+            "name": "redmine_rename_wiki_page",
+            "description": "重新命名 Wiki 頁面。舊頁面預設保留（內容改為指向新頁面，版本歷史與附件仍在舊頁面），回傳子頁面改掛結果",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "project_id": { "type": "string", "description": "專案 ID、識別碼或名稱" },
+                    "title": { "type": "string" },
+                    "new_title": { "type": "string" },
+                    "delete_old": { "type": "boolean", "description": "所有子頁面改掛成功後刪除舊頁面（會永久失去舊頁面的版本歷史與附件）" }
+                },
+                "required": ["project_id", "title", "new_title"]
+            }
+        }),
 
         // Files & Attachments
         json!({
@@ -267,7 +609,7 @@ pub fn get_tool_definitions() -> Vec<Value> {
             "description": "專案檔案",
             "inputSchema": {
                 "type": "object",
-                "properties": { "project_id": { "type": "string" } },
+                "properties": { "project_id": { "type": "string", "description": "專案 ID、識別碼或名稱" } },
                 "required": ["project_id"]
             }
         }),
@@ -280,6 +622,52 @@ pub fn get_tool_definitions() -> Vec<Value> {
                 "required": ["id"]
             }
         }),
+        json!({
+            "name": "redmine_get_attachment_content",
+            "description": "讀取附件內容：圖片 (PNG/JPEG/GIF/WebP) 回傳圖片，文字檔回傳文字，其他二進位檔請用 redmine_download",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": "number" },
+                    "max_bytes": { "type": "number", "description": "大小上限（不可超過 REDMINE_MAX_ATTACHMENT_BYTES，預設 5 MiB）" }
+                },
+                "required": ["id"]
+            }
+        }),
+        json!({
+            "name": "redmine_update_attachment",
+            "description": "更新附件檔名或說明",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": "number" },
+                    "filename": { "type": "string" },
+                    "description": { "type": "string" }
+                },
+                "required": ["id"]
+            }
+        }),
+        json!({
+            "name": "redmine_delete_attachment",
+            "description": "刪除附件",
+            "inputSchema": {
+                "type": "object",
+                "properties": { "id": { "type": "number" } },
+                "required": ["id"]
+            }
+        }),
+        json!({
+            "name": "redmine_get_attachment_thumbnail",
+            "description": "附件縮圖（回傳圖片，僅適用圖片附件）",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": "number" },
+                    "size": { "type": "number", "description": "縮圖邊長像素（預設由伺服器決定）" }
+                },
+                "required": ["id"]
+            }
+        }),
         json!({
             "name": "redmine_upload",
             "description": "上傳檔案",
@@ -292,6 +680,22 @@ pub fn get_tool_definitions() -> Vec<Value> {
                 "required": ["file_path"]
             }
         }),
+        json!({
+            "name": "redmine_create_project_file",
+            "description": "上傳檔案到專案檔案區（可關聯版本）。提供 file_path 或既有的上傳 token",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "project_id": { "type": "string", "description": "專案 ID、識別碼或名稱" },
+                    "file_path": { "type": "string" },
+                    "token": { "type": "string", "description": "redmine_upload 取得的 token" },
+                    "version_id": { "type": "number" },
+                    "filename": { "type": "string" },
+                    "description": { "type": "string" }
+                },
+                "required": ["project_id"]
+            }
+        }),
         json!({
             "name": "redmine_download",
             "description": "下載附件",
@@ -316,7 +720,9 @@ pub fn get_tool_definitions() -> Vec<Value> {
                     "scope": { "type": "string", "description": "資源類型篩選，如 issues, wiki_pages, news（可逗號分隔）" },
                     "project_id": { "type": "string", "description": "限定專案" },
                     "limit": { "type": "number" },
-                    "offset": { "type": "number" }
+                    "offset": { "type": "number" },
+                    "fetch_all": { "type": "boolean", "description": "自動分頁取得全部（忽略 limit/offset）" },
+                    "max_items": { "type": "number", "description": "fetch_all 的筆數上限（預設 1000，最多 10000）" }
                 },
                 "required": ["q"]
             }
@@ -338,12 +744,75 @@ pub fn get_tool_definitions() -> Vec<Value> {
             "description": "群組列表",
             "inputSchema": { "type": "object", "properties": {} }
         }),
+        json!({
+            "name": "redmine_get_group",
+            "description": "群組詳情：成員與專案角色（id 可用群組名稱）",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": ["number", "string"], "description": "群組 ID 或名稱" }
+                },
+                "required": ["id"]
+            }
+        }),
+
+        // News
         json!({
             "name": "redmine_get_news",
             "description": "新聞列表",
             "inputSchema": {
                 "type": "object",
-                "properties": { "project_id": { "type": "string" } }
+                "properties": {
+                    "project_id": { "type": "string", "description": "專案 ID、識別碼或名稱" },
+                    "limit": { "type": "number" },
+                    "offset": { "type": "number" }
+                }
+            }
+        }),
+        json!({
+            "name": "redmine_get_news_item",
+            "description": "新聞詳情（含留言）",
+            "inputSchema": {
+                "type": "object",
+                "properties": { "id": { "type": "number" } },
+                "required": ["id"]
+            }
+        }),
+        json!({
+            "name": "redmine_create_news",
+            "description": "發布新聞",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "project_id": { "type": "string", "description": "專案 ID、識別碼或名稱" },
+                    "title": { "type": "string" },
+                    "summary": { "type": "string" },
+                    "description": { "type": "string" }
+                },
+                "required": ["project_id", "title"]
+            }
+        }),
+        json!({
+            "name": "redmine_update_news",
+            "description": "更新新聞",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": "number" },
+                    "title": { "type": "string" },
+                    "summary": { "type": "string" },
+                    "description": { "type": "string" }
+                },
+                "required": ["id"]
+            }
+        }),
+        json!({
+            "name": "redmine_delete_news",
+            "description": "刪除新聞",
+            "inputSchema": {
+                "type": "object",
+                "properties": { "id": { "type": "number" } },
+                "required": ["id"]
             }
         }),
 